@@ -0,0 +1,40 @@
+use std::hint::black_box;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use strptime::Parser;
+
+const CASES: &[(&str, &str, &str)] = &[
+  ("ymd", "%Y-%m-%d", "2012-04-21"),
+  ("iso8601", "%Y-%m-%dT%H:%M:%S%.6f%z", "2012-04-21T11:00:00.000000-0400"),
+  ("names", "%A, %B %-d, %Y %I:%M %p", "Saturday, April 21, 2012 11:00 AM"),
+];
+
+fn parse(c: &mut Criterion) {
+  let mut group = c.benchmark_group("parse");
+  for &(name, fmt, input) in CASES {
+    let parser = Parser::new(fmt);
+    group.bench_function(name, |b| b.iter(|| parser.parse(black_box(input))));
+  }
+  group.finish();
+}
+
+/// Compare formats that take the fixed-width fast path against equivalent formats that do not
+/// (a `-` modifier on the final field makes its width variable, but matches the same input).
+fn fixed_width(c: &mut Criterion) {
+  let mut group = c.benchmark_group("fixed_width");
+  for (name, fixed, general, input) in [
+    ("compact", "%Y%m%d%H%M%S", "%Y%m%d%H%M%-S", "20120421110000"),
+    ("iso8601", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M:%-S", "2012-04-21T11:00:00"),
+  ] {
+    let (fixed, general) = (Parser::new(fixed), Parser::new(general));
+    group.bench_function(format!("{name}/fixed"), |b| b.iter(|| fixed.parse(black_box(input))));
+    group
+      .bench_function(format!("{name}/general"), |b| b.iter(|| general.parse(black_box(input))));
+  }
+  group.finish();
+}
+
+criterion_group!(benches, parse, fixed_width);
+criterion_main!(benches);
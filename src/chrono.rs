@@ -0,0 +1,127 @@
+//! Conversions into [`chrono`] types, enabled by the `chrono` feature.
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+
+use crate::error::ErrorKind;
+use crate::ParseError;
+use crate::ParseResult;
+use crate::Parser;
+use crate::RawDate;
+use crate::RawDateTime;
+use crate::RawTime;
+
+impl Parser {
+  /// Parse the date and time provided directly into a [`chrono`] type.
+  ///
+  /// Any type that can be converted from a [`RawDateTime`] may be requested, including
+  /// [`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`], and [`DateTime<FixedOffset>`].
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use chrono::NaiveDate;
+  /// use strptime::Parser;
+  /// let date = Parser::new("%Y-%m-%d").parse_chrono::<NaiveDate>("2012-04-21")?;
+  /// assert_eq!(date, NaiveDate::from_ymd_opt(2012, 4, 21).unwrap());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn parse_chrono<T>(&self, date_str: impl AsRef<str>) -> ParseResult<T>
+  where
+    T: TryFrom<RawDateTime, Error = ParseError>, {
+    T::try_from(self.parse(date_str)?)
+  }
+}
+
+impl TryFrom<RawDate> for NaiveDate {
+  type Error = ParseError;
+
+  fn try_from(value: RawDate) -> ParseResult<Self> {
+    naive_date(&value)
+  }
+}
+
+impl TryFrom<RawTime> for NaiveTime {
+  type Error = ParseError;
+
+  fn try_from(value: RawTime) -> ParseResult<Self> {
+    naive_time(&value)
+  }
+}
+
+impl TryFrom<RawDateTime> for NaiveDate {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    naive_date(&value.date()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for NaiveTime {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    naive_time(&value.time()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for NaiveDateTime {
+  type Error = ParseError;
+
+  /// Convert to a [`NaiveDateTime`]. A missing time is treated as midnight, and the end of the day
+  /// (`24:00`) as midnight of the following day.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    naive_date_time(&value)
+  }
+}
+
+impl TryFrom<RawDateTime> for DateTime<FixedOffset> {
+  type Error = ParseError;
+
+  /// Convert to a [`DateTime<FixedOffset>`]. A missing time is treated as midnight, but a UTC
+  /// offset is required.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    let offset = value.time.and_then(|t| t.utc_offset).ok_or(ErrorKind::MissingUtcOffset)?;
+    let offset = FixedOffset::east_opt(offset).ok_or(ErrorKind::OutOfRange)?;
+    offset
+      .from_local_datetime(&naive_date_time(&value)?)
+      .single()
+      .ok_or_else(|| ParseError::from(ErrorKind::OutOfRange))
+  }
+}
+
+fn naive_date_time(value: &RawDateTime) -> ParseResult<NaiveDateTime> {
+  let (date, time) = (naive_date(&value.date()?)?, value.time.unwrap_or_default());
+  match time.is_end_of_day() {
+    // The end of one day is the same instant as the start of the next.
+    true => Ok(date.succ_opt().ok_or(ErrorKind::OutOfRange)?.and_time(NaiveTime::MIN)),
+    false => Ok(date.and_time(naive_time(&time)?)),
+  }
+}
+
+fn naive_date(date: &RawDate) -> ParseResult<NaiveDate> {
+  date.assert_complete(false)?;
+  NaiveDate::from_ymd_opt(date.year(), date.month().into(), date.day().into())
+    .ok_or_else(|| ParseError::from(ErrorKind::OutOfRange))
+}
+
+fn naive_time(time: &RawTime) -> ParseResult<NaiveTime> {
+  // Chrono represents a leap second as an overlong second 59.
+  let (second, leap) = match time.is_leap_second() {
+    true => (59, 1_000_000_000),
+    false => (time.second(), 0),
+  };
+  NaiveTime::from_hms_nano_opt(
+    time.hour().into(),
+    time.minute().into(),
+    second.into(),
+    time.nanosecond() as u32 + leap,
+  )
+  .ok_or_else(|| ParseError::from(ErrorKind::OutOfRange))
+}
@@ -1,49 +1,146 @@
-use std::error::Error;
-use std::fmt::Display;
-use std::fmt::Formatter;
-use std::fmt::Result;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result;
 
 /// Errors occurring during parsing.
+///
+/// Without the `alloc` feature, errors carry only their kind and index. Errors that occur after
+/// parsing has finished (such as when converting a parsed value to another type) do not have
+/// access to the input, and so have an empty `src`.
+///
+/// Errors raised while consuming input also record the part of the format string being processed
+/// and, where the input did not match it, what was expected and what was found instead:
+///
+/// ```
+/// use strptime::Expected;
+/// use strptime::Parser;
+/// let err = Parser::new("%Y-%m-%d").parse("2012-4-21").unwrap_err();
+/// assert_eq!(err.index, Some(5));
+/// assert_eq!(err.specifier, Some("%m"));
+/// assert_eq!(err.format_index, Some(3));
+/// assert_eq!(err.expected, Some(Expected::Digits { min: 2, max: 2 }));
+/// assert_eq!(err.found, Some('-'));
+/// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
 pub struct ParseError {
-  /// An owned copy of the input string.
+  /// An owned copy of the input string. Input passed as bytes is converted lossily.
+  #[cfg(feature = "alloc")]
   pub src: String,
   /// The index in the input string where the error occurred.
   pub index: Option<usize>,
   /// A machine-readable explanation of the error.
   pub kind: ErrorKind,
+  /// The byte index in the format string of the specifier or literal character being processed
+  /// when the error occurred.
+  pub format_index: Option<usize>,
+  /// The specifier being processed when the error occurred, along with its modifiers (such as
+  /// `%-d`). This is `None` for errors in literal characters, and is not deserialized.
+  #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+  pub specifier: Option<&'static str>,
+  /// What the input was expected to contain at `index`.
+  pub expected: Option<Expected>,
+  /// The first character that did not match the expectation, or `None` if the input ended first.
+  /// This is only meaningful if `expected` is set, and is always `None` for
+  /// [`Expected::Range`], where the value as a whole was rejected.
+  pub found: Option<char>,
 }
 
 impl ParseError {
-  pub(crate) fn new(src: &str, kind: ErrorKind) -> Self {
-    Self { src: src.into(), index: None, kind }
+  #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+  pub(crate) fn new(src: &[u8], kind: ErrorKind) -> Self {
+    Self {
+      #[cfg(feature = "alloc")]
+      src: String::from_utf8_lossy(src).into_owned(),
+      index: None,
+      kind,
+      format_index: None,
+      specifier: None,
+      expected: None,
+      found: None,
+    }
+  }
+
+  /// Attach the input to an error that was created without it.
+  #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+  pub(crate) fn with_src(mut self, src: &[u8]) -> Self {
+    #[cfg(feature = "alloc")]
+    {
+      self.src = String::from_utf8_lossy(src).into_owned();
+    }
+    self
   }
 
   pub(crate) fn at_index(mut self, ix: usize) -> Self {
     self.index = Some(ix);
     self
   }
+
+  pub(crate) fn in_format(mut self, ix: usize, specifier: Option<&'static str>) -> Self {
+    self.format_index = Some(ix);
+    self.specifier = specifier;
+    self
+  }
+
+  pub(crate) fn expecting(mut self, expected: Expected, found: Option<char>) -> Self {
+    self.expected = Some(expected);
+    self.found = found;
+    self
+  }
+
+  /// Write the kind of error, along with the index if given, and any expectation and location in
+  /// the format string.
+  fn fmt_details(&self, f: &mut Formatter<'_>, index: Option<usize>) -> Result {
+    write!(f, "{}", self.kind)?;
+    if let Some(ix) = index {
+      write!(f, " (at index {ix})")?;
+    }
+    if let Some(expected) = self.expected {
+      match (expected, self.found) {
+        (Expected::Range { .. } | Expected::UtcOffset, _) => write!(f, ": expected {expected}")?,
+        (_, Some(ch)) => write!(f, ": expected {expected}, found `{ch}`")?,
+        (_, None) => write!(f, ": expected {expected}, found the end of the input")?,
+      }
+    }
+    match (self.specifier, self.format_index) {
+      (Some(spec), Some(ix)) => write!(f, " (`{spec}` at format index {ix})"),
+      (None, Some(ix)) => write!(f, " (format index {ix})"),
+      _ => Ok(()),
+    }
+  }
+}
+
+impl From<ErrorKind> for ParseError {
+  /// Create an error that is not associated with any particular input.
+  fn from(kind: ErrorKind) -> Self {
+    Self::new(b"", kind)
+  }
 }
 
 impl Display for ParseError {
   fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-    write!(
-      f,
-      "{}\n{}\n{}",
-      self.src,
-      match self.index {
-        Some(ix) => format!("{}^-----", " ".repeat(ix)),
-        None => String::new(),
-      },
-      self.kind
-    )
+    #[cfg(feature = "alloc")]
+    if !self.src.is_empty() {
+      writeln!(f, "{}", self.src)?;
+      if let Some(ix) = self.index {
+        write!(f, "{:ix$}^-----", "")?;
+      }
+      f.write_str("\n")?;
+      return self.fmt_details(f, None);
+    }
+    self.fmt_details(f, self.index)
   }
 }
 
-impl Error for ParseError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
 
 /// Potential errors that occur during parsing.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum ErrorKind {
   Ambiguous,
@@ -53,6 +150,8 @@ pub enum ErrorKind {
   InvalidFormat,
   MissingDate,
   MissingTime,
+  MissingUtcOffset,
+  OutOfRange,
   Unexpected,
 }
 
@@ -66,7 +165,58 @@ impl Display for ErrorKind {
       Self::InvalidFormat => "Could not parse format string",
       Self::MissingDate => "Parsing successful, but no date found",
       Self::MissingTime => "Parsing successful, but no time found",
+      Self::MissingUtcOffset => "Parsing successful, but no UTC offset found",
+      Self::OutOfRange => "Value is out of range",
       Self::Unexpected => "Input does not conform to format string",
     })
   }
 }
+
+/// What the input was expected to contain when a parse error occurred.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum Expected {
+  /// A literal character from the format string.
+  Char(char),
+  /// Between `min` and `max` digits, inclusive. `max` is `usize::MAX` if there is no limit.
+  Digits { min: usize, max: usize },
+  /// A value between `min` and `max`, inclusive.
+  Range { min: u32, max: u32 },
+  /// A sign (`+` or `-`).
+  Sign,
+  /// A UTC offset of at most 23 hours and 59 minutes.
+  UtcOffset,
+  /// A month name or abbreviation.
+  MonthName,
+  /// A weekday name or abbreviation.
+  WeekdayName,
+  /// A marker for before or after noon (such as `AM` or `PM`).
+  AmPm,
+  /// An era name (such as `BC` or `AD`).
+  EraName,
+  /// The end of the input.
+  End,
+}
+
+impl Display for Expected {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    match *self {
+      Self::Char(ch) => write!(f, "`{ch}`"),
+      Self::Digits { min: 1, max: 1 } => write!(f, "1 digit"),
+      Self::Digits { min, max } if min == max => write!(f, "{min} digits"),
+      Self::Digits { min: 1, max: usize::MAX } => write!(f, "at least 1 digit"),
+      Self::Digits { min, max: usize::MAX } => write!(f, "at least {min} digits"),
+      Self::Digits { min, max } => write!(f, "{min} to {max} digits"),
+      Self::Range { min, max } if min == max => write!(f, "the value {min}"),
+      Self::Range { min, max } => write!(f, "a value from {min} to {max}"),
+      Self::Sign => write!(f, "`+` or `-`"),
+      Self::UtcOffset => write!(f, "an offset from -2359 to +2359"),
+      Self::MonthName => write!(f, "a month name"),
+      Self::WeekdayName => write!(f, "a weekday name"),
+      Self::AmPm => write!(f, "AM or PM"),
+      Self::EraName => write!(f, "an era name"),
+      Self::End => write!(f, "the end of the input"),
+    }
+  }
+}
@@ -0,0 +1,172 @@
+use core::ops::Range;
+
+use crate::parser::OnceParser;
+use crate::Parser;
+use crate::RawDateTime;
+
+/// An iterator over every non-overlapping date and time found in a string.
+///
+/// This struct is created by [`Parser::find_iter`].
+#[must_use]
+pub struct FindIter<'p, 't> {
+  parser: &'p Parser,
+  text: &'t str,
+  anchor: Anchor,
+  literal: Option<Literal>,
+  pos: usize,
+}
+
+impl<'p, 't> FindIter<'p, 't> {
+  pub(crate) fn new(parser: &'p Parser, text: &'t str) -> Self {
+    let (anchor, literal) = (Anchor::for_format(parser), Literal::for_format(parser));
+    Self { parser, text, anchor, literal, pos: 0 }
+  }
+}
+
+impl<'p, 't> Iterator for FindIter<'p, 't> {
+  type Item = (Range<usize>, RawDateTime);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.pos < self.text.len() {
+      // Skip ahead to the next position where a match could possibly begin.
+      let rest = &self.text[self.pos..];
+      self.pos += rest.find(|c| self.anchor.matches(c))?;
+      if let Some(literal) = self.literal {
+        // A match must contain the literal within a bounded distance of its beginning; if the
+        // next occurrence is further away, skip ahead to the first position it could belong to.
+        let offset = self.text[self.pos..].find(literal.ch)?;
+        if offset > literal.max_offset {
+          self.pos += offset - literal.max_offset;
+          while !self.text.is_char_boundary(self.pos) {
+            self.pos += 1;
+          }
+          continue;
+        }
+      }
+      let rest = &self.text[self.pos..];
+      let result =
+        OnceParser::new(self.parser, rest.as_bytes()).without_error_src().parse_prefix();
+      match result {
+        Ok((answer, consumed)) if consumed > 0 => {
+          let start = self.pos;
+          self.pos += consumed;
+          return Some((start..self.pos, answer));
+        },
+        _ => self.pos += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+      }
+    }
+    None
+  }
+}
+
+/// The kind of character that a match must begin with, derived from the beginning of the format
+/// string (and, for names, from the names that the parser accepts).
+#[derive(Clone, Copy, Debug)]
+enum Anchor {
+  Char(char),
+  Digit,
+  DigitOrSign,
+  Alphabetic,
+  Sign,
+  Any,
+}
+
+impl Anchor {
+  fn for_format(parser: &Parser) -> Self {
+    let (opts, locale) = (&parser.opts, parser.opts.locale);
+    let mut chars = parser.fmt.chars();
+    match chars.next() {
+      Some('%') => {},
+      Some(ch) => return Self::Char(ch),
+      None => return Self::Any,
+    }
+    while let Some(ch) = chars.next() {
+      return match ch {
+        'Y' => Self::DigitOrSign,
+        'C' | 'y' | 'q' | 'm' | 'd' | 'H' | 'I' | 'M' | 'S' | 'f' => Self::Digit,
+        'b' | 'h' | 'B' => Self::for_names(
+          locale.month_names.iter().chain(&locale.month_abbrs).copied(),
+          opts.month_names,
+        ),
+        'a' | 'A' => Self::for_names(
+          locale.weekday_names.iter().chain(&locale.weekday_abbrs).copied(),
+          opts.weekday_names,
+        ),
+        'p' | 'P' => Self::for_names(locale.am_pm.iter().copied(), &[]),
+        'z' => Self::Sign,
+        '.' => Self::Char('.'),
+        'E' => match chars.next() {
+          Some('C') =>
+            Self::for_names(locale.era_names.iter().flat_map(|n| n.iter()).copied(), &[]),
+          Some('y') => Self::Digit,
+          _ => Self::Any,
+        },
+        // Padding modifiers for numeric fields may allow a leading space; others only change
+        // the field width.
+        '-' | '0' | '3' | '6' | '9' => continue,
+        _ => Self::Any,
+      };
+    }
+    Self::Any
+  }
+
+  /// Anchor on letters if every name begins with one, which is true of most locales.
+  fn for_names<'n>(names: impl Iterator<Item = &'n str>, custom: &[(&'n str, u8)]) -> Self {
+    let mut names = names.chain(custom.iter().map(|(name, _)| *name));
+    match names.all(|name| name.chars().next().is_some_and(char::is_alphabetic)) {
+      true => Self::Alphabetic,
+      false => Self::Any,
+    }
+  }
+
+  fn matches(&self, ch: char) -> bool {
+    match self {
+      Self::Char(expected) => ch == *expected,
+      Self::Digit => ch.is_numeric(),
+      Self::DigitOrSign => ch.is_numeric() || ch == '+' || ch == '-',
+      Self::Alphabetic => ch.is_alphabetic(),
+      Self::Sign => ch == '+' || ch == '-',
+      Self::Any => true,
+    }
+  }
+}
+
+/// The first literal character in the format string, which every match must contain, along with
+/// the greatest number of bytes that can precede it.
+#[derive(Clone, Copy, Debug)]
+struct Literal {
+  ch: char,
+  max_offset: usize,
+}
+
+impl Literal {
+  /// Find the first literal in the parser's format, provided that the specifiers before it have a
+  /// bounded width.
+  fn for_format(parser: &Parser) -> Option<Self> {
+    let opts = &parser.opts;
+    let digit_len = if opts.unicode_digits { 4 } else { 1 };
+    let (mut chars, mut max_offset) = (parser.fmt.chars(), 0usize);
+    while let Some(ch) = chars.next() {
+      if ch != '%' {
+        return Some(Self { ch, max_offset });
+      }
+      // The width of each specifier, as a number of ASCII characters and of digits.
+      let (ascii, digits) = loop {
+        match chars.next()? {
+          '.' => max_offset = max_offset.saturating_add(1),
+          '0' | '3' | '6' | '9' => {},
+          'Y' => break (1, opts.year_digits.1),
+          'C' | 'y' | 'm' | 'd' | 'e' | 'H' | 'k' | 'I' | 'M' | 'S' => break (0, 2),
+          'q' => break (0, 1),
+          'f' => break (0, 9),
+          'z' => break (1, 4),
+          // Names, and fields with `-` or space padding, have no maximum width.
+          _ => return None,
+        }
+      };
+      max_offset =
+        max_offset.saturating_add(ascii).saturating_add(digits.saturating_mul(digit_len));
+    }
+    None
+  }
+}
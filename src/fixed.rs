@@ -0,0 +1,125 @@
+//! A fast path for formats in which every field has a fixed width, such as `%Y%m%d%H%M%S` or
+//! `%Y-%m-%dT%H:%M:%S%.6f%z`.
+//!
+//! Such formats place every field at a known byte offset, so the input can be read by direct
+//! indexing rather than character by character. The fast path only ever succeeds where the general
+//! parser would produce the same result; on any mismatch it declines, and the general parser runs
+//! (and reports the error) instead.
+
+use crate::RawDateTime;
+
+/// Determine the length in bytes of every input matching the given format, or `None` if the format
+/// contains a field whose width can vary.
+pub(crate) const fn width(fmt: &str) -> Option<usize> {
+  let fmt = fmt.as_bytes();
+  let (mut i, mut width) = (0, 0);
+  while i < fmt.len() {
+    if fmt[i] != b'%' {
+      // Literals must be ASCII, so that the format and input advance in lockstep.
+      if !fmt[i].is_ascii() {
+        return None;
+      }
+      width += 1;
+      i += 1;
+      continue;
+    }
+    i += 1;
+    if i < fmt.len() && fmt[i] == b'.' {
+      width += 1;
+      i += 1;
+    }
+    let mut nano_digits = None;
+    if i < fmt.len() && matches!(fmt[i], b'3' | b'6' | b'9') {
+      nano_digits = Some((fmt[i] - b'0') as usize);
+      i += 1;
+    }
+    if i >= fmt.len() {
+      return None;
+    }
+    width += match (fmt[i], nano_digits) {
+      (b'Y', None) => 4,
+      (b'm' | b'd' | b'H' | b'M' | b'S', None) => 2,
+      (b'f', Some(digits)) => digits,
+      (b'f', None) => 9,
+      (b'z', None) => 5,
+      _ => return None,
+    };
+    i += 1;
+  }
+  Some(width)
+}
+
+/// Parse the beginning of the input using a format for which [`width`] returned a value.
+///
+/// Returns `None` if the input does not match, in which case the general parser should be used.
+pub(crate) fn parse(fmt: &str, input: &[u8]) -> Option<RawDateTime> {
+  let mut answer = RawDateTime { date: None, time: None };
+  let fmt = fmt.as_bytes();
+  let (mut i, mut pos) = (0, 0);
+  while i < fmt.len() {
+    if fmt[i] != b'%' {
+      if *input.get(pos)? != fmt[i] {
+        return None;
+      }
+      pos += 1;
+      i += 1;
+      continue;
+    }
+    i += 1;
+    if fmt[i] == b'.' {
+      if *input.get(pos)? != b'.' {
+        return None;
+      }
+      pos += 1;
+      i += 1;
+    }
+    let mut nano_digits = 9;
+    if matches!(fmt[i], b'3' | b'6' | b'9') {
+      nano_digits = (fmt[i] - b'0') as usize;
+      i += 1;
+    }
+    match fmt[i] {
+      b'Y' => answer.set_year(digits(input, &mut pos, 4)? as i32),
+      b'm' => answer.set_month(digits(input, &mut pos, 2)? as u8),
+      b'd' => answer.set_day(digits(input, &mut pos, 2)? as u8),
+      b'H' => answer.set_hour(digits(input, &mut pos, 2)? as u8),
+      b'M' => answer.set_minute(digits(input, &mut pos, 2)? as u8),
+      b'S' => answer.set_second(digits(input, &mut pos, 2)? as u8),
+      b'f' => {
+        let nanos = digits(input, &mut pos, nano_digits)? * 10u64.pow(9 - nano_digits as u32);
+        answer.set_nanosecond(nanos, nano_digits as u8);
+      },
+      b'z' => {
+        let sign = match input.get(pos)? {
+          b'+' => 1,
+          b'-' => -1,
+          _ => return None,
+        };
+        pos += 1;
+        let hhmm = digits(input, &mut pos, 4)? as i32;
+        if hhmm / 100 > 23 || hhmm % 100 > 59 {
+          return None;
+        }
+        answer.set_utc_offset(hhmm * sign);
+      },
+      _ => unreachable!("Format was checked by `width`"),
+    }
+    i += 1;
+  }
+  Some(answer)
+}
+
+/// Read exactly `n` ASCII digits from the input at the given position, and advance it.
+#[inline]
+fn digits(input: &[u8], pos: &mut usize, n: usize) -> Option<u64> {
+  let bytes = input.get(*pos..*pos + n)?;
+  let mut value = 0;
+  for b in bytes {
+    if !b.is_ascii_digit() {
+      return None;
+    }
+    value = value * 10 + (b - b'0') as u64;
+  }
+  *pos += n;
+  Some(value)
+}
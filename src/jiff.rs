@@ -0,0 +1,109 @@
+//! Conversions into [`jiff`] types, enabled by the `jiff` feature.
+
+use jiff::civil::Date;
+use jiff::civil::DateTime;
+use jiff::civil::Time;
+use jiff::tz::Offset;
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
+use jiff::Zoned;
+
+use crate::error::ErrorKind;
+use crate::ParseError;
+use crate::ParseResult;
+use crate::RawDate;
+use crate::RawDateTime;
+use crate::RawTime;
+
+impl TryFrom<RawDate> for Date {
+  type Error = ParseError;
+
+  fn try_from(value: RawDate) -> ParseResult<Self> {
+    date(&value)
+  }
+}
+
+impl TryFrom<RawTime> for Time {
+  type Error = ParseError;
+
+  fn try_from(value: RawTime) -> ParseResult<Self> {
+    time(&value)
+  }
+}
+
+impl TryFrom<RawDateTime> for Date {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    date(&value.date()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for Time {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    time(&value.time()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for DateTime {
+  type Error = ParseError;
+
+  /// Convert to a civil [`DateTime`]. A missing time is treated as midnight, and the end of the
+  /// day (`24:00`) as midnight of the following day.
+  ///
+  /// Jiff does not support leap seconds, so they send `OutOfRange`.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    date_time(&value)
+  }
+}
+
+impl TryFrom<RawDateTime> for Timestamp {
+  type Error = ParseError;
+
+  /// Convert to a [`Timestamp`]. A missing time is treated as midnight, but a UTC offset is
+  /// required.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    offset(&value)?
+      .to_timestamp(date_time(&value)?)
+      .map_err(|_| ParseError::from(ErrorKind::OutOfRange))
+  }
+}
+
+impl TryFrom<RawDateTime> for Zoned {
+  type Error = ParseError;
+
+  /// Convert to a [`Zoned`] datetime in a fixed-offset time zone. A missing time is treated as
+  /// midnight, but a UTC offset is required.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    let tz = TimeZone::fixed(offset(&value)?);
+    Ok(Timestamp::try_from(value)?.to_zoned(tz))
+  }
+}
+
+fn offset(value: &RawDateTime) -> ParseResult<Offset> {
+  let seconds = value.time.and_then(|t| t.utc_offset).ok_or(ErrorKind::MissingUtcOffset)?;
+  Offset::from_seconds(seconds).map_err(|_| ParseError::from(ErrorKind::OutOfRange))
+}
+
+fn date_time(value: &RawDateTime) -> ParseResult<DateTime> {
+  let (date, raw_time) = (date(&value.date()?)?, value.time.unwrap_or_default());
+  match raw_time.is_end_of_day() {
+    // The end of one day is the same instant as the start of the next.
+    true => Ok(date.tomorrow().map_err(|_| ParseError::from(ErrorKind::OutOfRange))?.into()),
+    false => Ok(date.to_datetime(time(&raw_time)?)),
+  }
+}
+
+fn date(date: &RawDate) -> ParseResult<Date> {
+  date.assert_complete(false)?;
+  let year = i16::try_from(date.year()).map_err(|_| ErrorKind::OutOfRange)?;
+  Date::new(year, date.month() as i8, date.day() as i8)
+    .map_err(|_| ParseError::from(ErrorKind::OutOfRange))
+}
+
+fn time(time: &RawTime) -> ParseResult<Time> {
+  Time::new(time.hour() as i8, time.minute() as i8, time.second() as i8, time.nanosecond() as i32)
+    .map_err(|_| ParseError::from(ErrorKind::OutOfRange))
+}
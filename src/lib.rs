@@ -3,16 +3,44 @@
 //! This library can be used to parse a date and time string into a [`RawDateTime`], which the user
 //! can then convert into whatever final type is needed, without taking on a larger time library as
 //! a dependency.
+//!
+//! The library supports `no_std` environments: disable the default `std` feature, and optionally
+//! enable the `alloc` feature to retain copies of the input in errors.
+
+#![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "chrono")]
+mod chrono;
 mod error;
+mod find;
+mod fixed;
+#[cfg(feature = "jiff")]
+mod jiff;
+mod locale;
 mod models;
+mod numerals;
 mod parser;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod tests;
+#[cfg(feature = "time")]
+mod time;
 
+pub use error::ErrorKind;
+pub use error::Expected;
 pub use error::ParseError;
+pub use find::FindIter;
+pub use locale::Locale;
+pub use models::DatePrecision;
 pub use models::RawDate;
 pub use models::RawDateTime;
 pub use models::RawTime;
+pub use models::TimePrecision;
 
 /// A result returned from date and time parsing.
 pub type ParseResult<T> = Result<T, ParseError>;
@@ -29,17 +57,39 @@ pub type ParseResult<T> = Result<T, ParseError>;
 ///
 /// | Code | Example | Description                                            |
 /// | ---- | ------- | ------------------------------------------------------ |
-/// | `Y`  | `2012`  | The year, zero-padded to 4 digits.                     |
+/// | `Y`  | `2012`  | The year, zero-padded to 4 digits, with optional sign. |
 /// | `C`  | `20`    | The year divided by 100, with the remainder discarded. |
 /// | `y`  | `12`    | The year modulo 100, zero-padded to 2 digits.          |
 ///
+/// Signed years are astronomical (`-0044` is 45 BC). See [`Parser::year_digits`] to accept a
+/// different number of digits for `%Y`, such as expanded years (`+12345`).
+///
+/// ## Era
+///
+/// | Code | Example | Description                                            |
+/// | ---- | ------- | ------------------------------------------------------ |
+/// | `EC` | `BC`    | The era (`BC` or `BCE`, and `AD` or `CE`, in English). |
+/// | `Ey` | `44`    | The year within the era, with any number of digits.    |
+///
+/// When an era is present, the year (from `%Ey`, `%Y`, or `%C` and `%y`) is counted from 1 within
+/// that era, and is converted to an astronomical year: `44 BC` is year -43.
+///
+/// ## Quarter
+///
+/// | Code | Example | Description                           |
+/// | ---- | ------- | ------------------------------------- |
+/// | `q`  | `2`     | The quarter of the year, from 1 to 4. |
+///
+/// A year and quarter without a month (as in `%Y-Q%q`) form a partial date; see
+/// [`Parser::allow_partial_dates`] and [`Parser::default_day`].
+///
 /// ## Month
 ///
 /// | Code       | Example | Description                                                       |
 /// | ---------- | ------- | ----------------------------------------------------------------- |
 /// | `m`        | `04`    | The month number, zero-padded to two digits (`01`` = January)     |
-/// | `b` or `h` | `Apr`   | The month's English name, abbreviated to three characters         |
-/// | `B`        | `April` | The month's English name (abbreviations >= 3 chars also accepted) |
+/// | `b` or `h` | `Apr`   | The month's abbreviated name                                      |
+/// | `B`        | `April` | The month's full name (abbreviations >= 3 chars also accepted)    |
 ///
 /// ## Day
 ///
@@ -48,12 +98,15 @@ pub type ParseResult<T> = Result<T, ParseError>;
 /// | `d`  | `21`    | The day of the month, zero padded to 2 digits. |
 /// | `e`  | `21`    | Same as `% d`.                                 |
 ///
+/// Months must be between 1 and 12, and days between 1 and 31. Whether the day exists in its
+/// month is not checked until the date is converted (such as by [`RawDateTime::unix_timestamp`]).
+///
 /// ## Weekday
 ///
 /// | Code | Example  | Description                                           |
 /// | ---- | -------- | ----------------------------------------------------- |
-/// | `a`  | `Sun`    | The English weekday, abbreviated to three characters. |
-/// | `A`  | `Sunday` | The English weekday (full name).                      |
+/// | `a`  | `Sun`    | The weekday's abbreviated name.                       |
+/// | `A`  | `Sunday` | The weekday's full name.                              |
 ///
 /// ## Hour
 ///
@@ -63,7 +116,7 @@ pub type ParseResult<T> = Result<T, ParseError>;
 /// | `I`  | `05`    | The hour, zero-padded to 2 digits, using the 12-hour clock. |
 /// | `k`  | `17`    | Same as `% H`.                                              |
 /// | `p`  | `PM`    | `AM` or `PM`                                                |
-/// | `P`  | `pm`    | `am` or `PM`                                                |
+/// | `P`  | `pm`    | `am` or `pm`                                                |
 ///
 /// ## Minute
 ///
@@ -77,11 +130,18 @@ pub type ParseResult<T> = Result<T, ParseError>;
 /// | ---- | ------- | ------------------------------------ |
 /// | `S`  | `45`    | The second, zero-padded to 2 digits. |
 ///
+/// Hours, minutes, and seconds must be in range: `24:00:00` and leap seconds (`23:59:60`) are only
+/// accepted if [`Parser::allow_end_of_day`] or [`Parser::allow_leap_seconds`] is set.
+///
 /// ## Nanosecond
 ///
-/// | Code | Example     | Description                              |
-/// | ---- | ----------- | ---------------------------------------- |
-/// | `f`  | `500000000` | The nanosecond, zero-padded to 9 digits. |
+/// | Code | Example     | Description                                                          |
+/// | ---- | ----------- | -------------------------------------------------------------------- |
+/// | `f`  | `500000000` | The nanosecond, zero-padded to 9 digits.                             |
+/// | `-f` | `5`         | The fraction of a second, with 1 to 9 digits (see below).            |
+///
+/// Digits beyond the ninth are an error for `%-f` unless [`Parser::fraction_overflow`] says
+/// otherwise. Any of these may be preceded by `.` (as in `%.-f`) to require a leading period.
 ///
 /// ## Time Zone Offset
 ///
@@ -89,18 +149,41 @@ pub type ParseResult<T> = Result<T, ParseError>;
 /// | ---- | ------- | -------------------------------- |
 /// | `z`  | `-0400` | The offset, as `MMSS`, from UTC. |
 ///
+/// Names of months and weekdays, and the `AM`/`PM` markers, are matched according to the parser's
+/// [`Locale`] (English by default); see [`Parser::locale`]. Names are matched ignoring case, using
+/// Unicode case folding (so `MÄRZ` matches `März`, and `GROSS` matches `groß`), while `AM`/`PM`
+/// markers must match exactly (or in lowercase, for `%P`).
+///
+/// ## Feature Flags
+///
+/// - `std` (default): Implementations of `std::error::Error`, and conversion into
+///   `std::time::SystemTime`. Implies `alloc`.
+/// - `alloc`: Errors retain an owned copy of the input. Without this feature, errors carry only
+///   their kind and index.
+/// - `chrono`: Conversions from the raw types into [`chrono`](::chrono) types, along with
+///   `Parser::parse_chrono`.
+/// - `jiff`: Conversions from the raw types into [`jiff`](::jiff) types, including
+///   [`Zoned`](::jiff::Zoned) values when a UTC offset was parsed.
+/// - `serde`: `Serialize` and `Deserialize` for the raw types and [`ParseError`], plus helpers for
+///   deserializing strings with a [`Parser`] (see the `serde` module).
+/// - `time`: Conversions from the raw types into [`time`](::time) types.
+///
 /// **Note:** The parser does not currently check for certain impossible combinations (such as
 /// declaring that April 21, 2012 was a Tuesday, when it was actually a Saturday). Currently,
 /// non-conclusive input (such as weekdays) are discarded. This will change in the future.
 pub struct Parser {
   fmt: &'static str,
+  fixed_width: Option<usize>,
   opts: ParseOptions,
 }
 
 impl Parser {
   /// Create a new date and time parser.
+  ///
+  /// If every field in the format has a fixed width (for example, `%Y-%m-%dT%H:%M:%S`), the parser
+  /// will read fields directly from their known positions, which is considerably faster.
   pub const fn new(fmt: &'static str) -> Self {
-    Self { fmt, opts: ParseOptions::new() }
+    Self { fmt, fixed_width: fixed::width(fmt), opts: ParseOptions::new() }
   }
 
   /// Parse the date and time provided.
@@ -109,8 +192,157 @@ impl Parser {
   /// `None` in the (common) case when one is parsing only a date or only a time. If a date is
   /// provided at all, it's guaranteed to be "complete enough" (e.g. it won't come back with a year
   /// and day and no month). Times are more permissive, with missing elements defaulting to 0.
+  ///
+  /// See [`Parser::allow_partial_dates`] to accept dates with only some components present.
+  ///
+  /// Successful parsing does not allocate; the returned value does not retain the input.
   pub fn parse(&self, date_str: impl AsRef<str>) -> ParseResult<RawDateTime> {
-    parser::OnceParser::new(self.fmt, date_str.as_ref(), self.opts).parse()
+    parser::OnceParser::new(self, date_str.as_ref().as_bytes()).parse()
+  }
+
+  /// Parse the date and time provided as bytes, such as a slice of a log buffer.
+  ///
+  /// The input need not be valid UTF-8; it is matched against the format directly, and any
+  /// non-ASCII characters required by the format are decoded as they are encountered. Error
+  /// indices are byte offsets into the input.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d");
+  /// assert_eq!(parser.parse_bytes(b"2012-04-21")?.date()?.day(), 21);
+  /// assert_eq!(parser.parse_bytes(b"2012-04-\xff1").unwrap_err().index, Some(8));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn parse_bytes(&self, date_bytes: &[u8]) -> ParseResult<RawDateTime> {
+    parser::OnceParser::new(self, date_bytes).parse()
+  }
+
+  /// Parse a date and time from the beginning of the string provided, and return it along with
+  /// the remainder of the string that was not consumed.
+  ///
+  /// This is useful for peeling a timestamp off of the front of a record, such as a log line.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d %H:%M:%S");
+  /// let (dt, rest) = parser.parse_prefix("2012-04-21 11:00:00 ERROR something")?;
+  /// assert_eq!(dt.time()?.hour(), 11);
+  /// assert_eq!(rest, " ERROR something");
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn parse_prefix<'a>(&self, date_str: &'a str) -> ParseResult<(RawDateTime, &'a str)> {
+    let (answer, consumed) = parser::OnceParser::new(self, date_str.as_bytes()).parse_prefix()?;
+    Ok((answer, &date_str[consumed..]))
+  }
+
+  /// Parse a date and time from the beginning of the bytes provided, and return it along with the
+  /// remaining bytes that were not consumed.
+  ///
+  /// See [`Parser::parse_bytes`] and [`Parser::parse_prefix`].
+  pub fn parse_bytes_prefix<'a>(
+    &self, date_bytes: &'a [u8],
+  ) -> ParseResult<(RawDateTime, &'a [u8])> {
+    let (answer, consumed) = parser::OnceParser::new(self, date_bytes).parse_prefix()?;
+    Ok((answer, &date_bytes[consumed..]))
+  }
+
+  /// Parse many dates and times, such as a column of timestamps, appending the results to `out`.
+  ///
+  /// Parsing stops at the first error, which is sent along with the index of the offending row;
+  /// rows before it will already have been appended. See [`Parser::parse_many_lenient`] to
+  /// continue past errors instead.
+  ///
+  /// This is a convenience over calling [`Parser::parse`] in a loop: the format is analyzed once,
+  /// when the parser is built, so there is no per-call setup for it to save.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d");
+  /// let mut dates = Vec::new();
+  /// parser.parse_many(["2012-04-21", "2012-04-22"], &mut dates).unwrap();
+  /// assert_eq!(dates.len(), 2);
+  /// let (row, _) = parser.parse_many(["2012-04-23", "04/24/2012"], &mut dates).unwrap_err();
+  /// assert_eq!((row, dates.len()), (1, 3));
+  /// ```
+  pub fn parse_many<S: AsRef<str>>(
+    &self, inputs: impl IntoIterator<Item = S>, out: &mut impl Extend<RawDateTime>,
+  ) -> Result<(), (usize, ParseError)> {
+    let mut error = None;
+    out.extend(inputs.into_iter().enumerate().map_while(|(row, s)| match self.parse(s) {
+      Ok(answer) => Some(answer),
+      Err(err) => {
+        error = Some((row, err));
+        None
+      },
+    }));
+    error.map_or(Ok(()), Err)
+  }
+
+  /// Parse many dates and times, such as a column of timestamps, without stopping at errors.
+  ///
+  /// One entry is appended to `out` for every input: the parsed value, or `None` if parsing
+  /// failed. Each failure is appended to `errors` along with the index of its row. Since the row
+  /// identifies the input, it is not copied into the error.
+  ///
+  /// Like [`Parser::parse_many`], this is a convenience over calling [`Parser::parse`] in a loop.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d");
+  /// let (mut dates, mut errors) = (Vec::new(), Vec::new());
+  /// parser.parse_many_lenient(["2012-04-21", "n/a", "2012-04-22"], &mut dates, &mut errors);
+  /// assert_eq!(dates.iter().filter(|d| d.is_some()).count(), 2);
+  /// assert_eq!(errors[0].0, 1);
+  /// ```
+  pub fn parse_many_lenient<S: AsRef<str>>(
+    &self, inputs: impl IntoIterator<Item = S>, out: &mut impl Extend<Option<RawDateTime>>,
+    errors: &mut impl Extend<(usize, ParseError)>,
+  ) {
+    out.extend(inputs.into_iter().enumerate().map(|(row, s)| {
+      match parser::OnceParser::new(self, s.as_ref().as_bytes()).without_error_src().parse() {
+        Ok(answer) => Some(answer),
+        Err(err) => {
+          errors.extend(Some((row, err)));
+          None
+        },
+      }
+    }));
+  }
+
+  /// Find every non-overlapping date and time in the given text.
+  ///
+  /// The iterator yields the byte range of each match along with the parsed value. Text that does
+  /// not match the format is skipped; the beginning of the format and its first literal character
+  /// are used to jump quickly to positions where a match could begin.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d");
+  /// let text = "Deployed 2012-04-21, rolled back 2012-04-22.";
+  /// let found: Vec<_> = parser.find_iter(text).collect();
+  /// assert_eq!(found.len(), 2);
+  /// assert_eq!(&text[found[0].0.clone()], "2012-04-21");
+  /// assert_eq!(found[1].1.date()?.day(), 22);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn find_iter<'p, 't>(&'p self, text: &'t str) -> FindIter<'p, 't> {
+    FindIter::new(self, text)
   }
 
   /// Provide a custom function to be used if only a modulo of 100 is provided for the year (as in
@@ -120,6 +352,9 @@ impl Parser {
   /// - `[00, 70)`: 21st century
   /// - `[70, 99]`: 20th century
   ///
+  /// The function is only called with values between 0 and 99; a larger value (which the `-`
+  /// padding modifier could allow) sends `OutOfRange`.
+  ///
   /// ## Example
   ///
   /// ```
@@ -132,21 +367,312 @@ impl Parser {
   /// # Ok(())
   /// # }
   /// ```
-  pub const fn modulo_year_resolution(mut self, modulo_year_resolution: fn(i16) -> i16) -> Self {
+  pub const fn modulo_year_resolution(mut self, modulo_year_resolution: fn(i32) -> i32) -> Self {
     self.opts.modulo_year_resolution = modulo_year_resolution;
     self
   }
+
+  /// Set the number of digits accepted by `%Y` (by default, exactly 4).
+  ///
+  /// The year may be preceded by a sign in any case. The `-` padding modifier (`%-Y`) accepts any
+  /// number of digits, regardless of this setting. A year that does not fit in an `i32` sends
+  /// `OutOfRange`.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if `min` is zero or greater than `max`.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d").year_digits(4, 6);
+  /// assert_eq!(parser.parse("+12345-04-21")?.date()?.year(), 12345);
+  /// assert_eq!(parser.parse("-0044-03-15")?.date()?.year(), -44);
+  /// assert!(parser.parse("123-04-21").is_err());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn year_digits(mut self, min: usize, max: usize) -> Self {
+    assert!(min > 0 && min <= max, "Year digits must satisfy 0 < min <= max");
+    self.opts.year_digits = (min, max);
+    // The fast path only understands four-digit years.
+    if min != 4 || max != 4 {
+      self.fixed_width = None;
+    }
+    self
+  }
+
+  /// Accept dates where only some of the components are present.
+  ///
+  /// By default, a date must provide a year, month, and day. With partial dates allowed, the
+  /// parser will also accept a year alone, a year and quarter, a year and month, or a month and
+  /// day. Use [`RawDate::precision`] and the `_opt` accessors (such as [`RawDate::day_opt`]) to
+  /// inspect the result.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::DatePrecision;
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%B %Y").allow_partial_dates();
+  /// let date = parser.parse("April 2012")?.date()?;
+  /// assert_eq!(date.precision(), DatePrecision::YearMonth);
+  /// assert_eq!(date.day_opt(), None);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn allow_partial_dates(mut self) -> Self {
+    self.opts.partial_dates = true;
+    self
+  }
+
+  /// Fill in a missing day with the given day, so that dates such as `April 2012` or `2012-Q2`
+  /// are complete. If the month is also missing, the first month of the quarter is used.
+  ///
+  /// If the month is shorter than the given day, its last day is used instead, so `31` selects
+  /// the end of the month.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if `day` is not between 1 and 31.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-Q%q").default_day(1);
+  /// let date = parser.parse("2012-Q2")?.date()?;
+  /// assert_eq!((date.year(), date.month(), date.day()), (2012, 4, 1));
+  /// assert_eq!(date.quarter(), 2);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn default_day(mut self, day: u8) -> Self {
+    assert!(day >= 1 && day <= 31, "The default day must be between 1 and 31");
+    self.opts.default_day = Some(day);
+    self
+  }
+
+  /// Set the locale used to match the names of months and weekdays, and the `AM`/`PM` markers.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Locale;
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%A %-d %B %Y").locale(&Locale::FRENCH);
+  /// assert_eq!(parser.parse("samedi 21 avril 2012")?.date()?.month(), 4);
+  /// let parser = Parser::new("%-d. %b %Y").locale(&Locale::GERMAN);
+  /// assert_eq!(parser.parse("21. Apr. 2012")?.date()?.month(), 4);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn locale(mut self, locale: &'static Locale) -> Self {
+    self.opts.locale = locale;
+    self
+  }
+
+  /// Accept decimal digits from any script (such as Arabic-Indic `٢٠١٢` or full-width `２０１２`)
+  /// wherever digits are expected, rather than only ASCII digits.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%Y-%m-%d").allow_unicode_digits();
+  /// assert_eq!(parser.parse("٢٠١٢-٠٤-٢١")?.date()?.year(), 2012);
+  /// assert!(Parser::new("%Y-%m-%d").parse("٢٠١٢-٠٤-٢١").is_err());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn allow_unicode_digits(mut self) -> Self {
+    self.opts.unicode_digits = true;
+    self
+  }
+
+  /// Accept leap seconds (a second of `60`, as in `23:59:60`).
+  ///
+  /// Leap seconds are reported as such by [`RawTime::is_leap_second`], rather than being
+  /// normalized to another time.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%H:%M:%S").allow_leap_seconds();
+  /// let time = parser.parse("23:59:60")?.time()?;
+  /// assert!(time.is_leap_second());
+  /// assert_eq!(time.second(), 60);
+  /// assert!(Parser::new("%H:%M:%S").parse("23:59:60").is_err());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn allow_leap_seconds(mut self) -> Self {
+    self.opts.leap_seconds = true;
+    self
+  }
+
+  /// Accept the end of the day as an hour of `24`, as permitted by ISO 8601 (`24:00:00`). The
+  /// minutes, seconds, and any fraction of a second must be zero.
+  ///
+  /// The end of the day is reported as such by [`RawTime::is_end_of_day`], rather than being
+  /// normalized to midnight of the following day.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%H:%M:%S").allow_end_of_day();
+  /// assert!(parser.parse("24:00:00")?.time()?.is_end_of_day());
+  /// assert!(parser.parse("24:00:01").is_err());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn allow_end_of_day(mut self) -> Self {
+    self.opts.end_of_day = true;
+    self
+  }
+
+  /// Set how digits beyond the ninth are handled by the variable-length fraction specifier
+  /// (`%-f`). By default, they are an error.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::FractionOverflow;
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%H:%M:%S%.-f");
+  /// assert_eq!(parser.parse("11:00:00.5")?.time()?.nanosecond(), 500_000_000);
+  /// assert!(parser.parse("11:00:00.1234567896").is_err());
+  /// let parser = Parser::new("%H:%M:%S%.-f").fraction_overflow(FractionOverflow::Round);
+  /// assert_eq!(parser.parse("11:00:00.1234567896")?.time()?.nanosecond(), 123_456_790);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn fraction_overflow(mut self, fraction_overflow: FractionOverflow) -> Self {
+    self.opts.fraction_overflow = fraction_overflow;
+    self
+  }
+
+  /// Accept additional month names, such as in-house abbreviations or fiscal month names, each
+  /// mapped to a month number (January = 1).
+  ///
+  /// Custom names are accepted by `%b`, `%h`, and `%B`, in addition to the names from the
+  /// [`Locale`]. They are matched case-insensitively and must match in their entirety. When more
+  /// than one name matches, the longest match is used.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if any month number is not between 1 and 12.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// static FISCAL: &[(&str, u8)] = &[("Period 1", 7), ("Period 10", 4), ("Sept", 9)];
+  /// let parser = Parser::new("%-d %b %Y").month_names(FISCAL);
+  /// assert_eq!(parser.parse("1 Period 10 2012")?.date()?.month(), 4);
+  /// assert_eq!(parser.parse("1 Sept 2012")?.date()?.month(), 9);
+  /// assert_eq!(parser.parse("1 Sep 2012")?.date()?.month(), 9);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn month_names(mut self, names: &'static [(&'static str, u8)]) -> Self {
+    let mut ix = 0;
+    while ix < names.len() {
+      assert!(matches!(names[ix].1, 1..=12), "Month numbers must be between 1 and 12");
+      ix += 1;
+    }
+    self.opts.month_names = names;
+    self
+  }
+
+  /// Accept additional weekday names, such as `Tues` or `Thur`, each mapped to a weekday number
+  /// (Sunday = 0).
+  ///
+  /// Custom names are accepted by `%a` and `%A`; see [`Parser::month_names`].
+  ///
+  /// ## Panics
+  ///
+  /// Panics if any weekday number is not between 0 and 6.
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let parser = Parser::new("%a %Y-%m-%d").weekday_names(&[("Tues", 2), ("Thur", 4)]);
+  /// assert!(parser.parse("Thur 2012-04-19").is_ok());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn weekday_names(mut self, names: &'static [(&'static str, u8)]) -> Self {
+    let mut ix = 0;
+    while ix < names.len() {
+      assert!(names[ix].1 <= 6, "Weekday numbers must be between 0 and 6");
+      ix += 1;
+    }
+    self.opts.weekday_names = names;
+    self
+  }
+}
+
+/// How to handle digits beyond nanosecond precision in a variable-length fraction of a second.
+///
+/// See [`Parser::fraction_overflow`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FractionOverflow {
+  /// Fail to parse the input.
+  #[default]
+  Error,
+  /// Discard the extra digits.
+  Truncate,
+  /// Round to the nearest nanosecond, with halves rounded up. A value that would round up to a
+  /// full second is instead capped at 999,999,999 nanoseconds.
+  Round,
 }
 
 /// Options for date and time parsing.
 #[derive(Clone, Copy)]
 pub(crate) struct ParseOptions {
-  modulo_year_resolution: fn(i16) -> i16,
+  modulo_year_resolution: fn(i32) -> i32,
+  year_digits: (usize, usize),
+  partial_dates: bool,
+  default_day: Option<u8>,
+  unicode_digits: bool,
+  leap_seconds: bool,
+  end_of_day: bool,
+  fraction_overflow: FractionOverflow,
+  locale: &'static Locale,
+  month_names: &'static [(&'static str, u8)],
+  weekday_names: &'static [(&'static str, u8)],
 }
 
 impl ParseOptions {
   /// Create a new parse options object.
   pub const fn new() -> Self {
-    Self { modulo_year_resolution: |y| if y >= 70 { 1900 + y } else { 2000 + y } }
+    Self {
+      modulo_year_resolution: |y| if y >= 70 { 1900 + y } else { 2000 + y },
+      year_digits: (4, 4),
+      partial_dates: false,
+      default_day: None,
+      unicode_digits: false,
+      leap_seconds: false,
+      end_of_day: false,
+      fraction_overflow: FractionOverflow::Error,
+      locale: &Locale::ENGLISH,
+      month_names: &[],
+      weekday_names: &[],
+    }
   }
 }
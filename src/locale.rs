@@ -0,0 +1,146 @@
+/// The names used for months, weekdays, and the time of day in a particular language.
+///
+/// A locale is set on a parser using [`Parser::locale`](crate::Parser::locale), and is consulted
+/// by the `%b`, `%B`, `%a`, `%A`, `%p`, `%P`, and `%EC` specifiers. Several locales are built in,
+/// and English is used by default. A custom locale can be built from an existing one and declared
+/// as a `static`:
+///
+/// ```
+/// # fn main() -> strptime::ParseResult<()> {
+/// use strptime::Locale;
+/// use strptime::Parser;
+/// static DUTCH: Locale = Locale::ENGLISH
+///   .with_month_names([
+///     "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september",
+///     "oktober", "november", "december",
+///   ])
+///   .with_month_abbrs([
+///     "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+///   ]);
+/// let parser = Parser::new("%-d %B %Y").locale(&DUTCH);
+/// assert_eq!(parser.parse("21 april 2012")?.date()?.month(), 4);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Locale {
+  /// The full names of the months, beginning with January.
+  pub month_names: [&'static str; 12],
+  /// The abbreviated names of the months, beginning with January.
+  pub month_abbrs: [&'static str; 12],
+  /// The full names of the weekdays, beginning with Sunday.
+  pub weekday_names: [&'static str; 7],
+  /// The abbreviated names of the weekdays, beginning with Sunday.
+  pub weekday_abbrs: [&'static str; 7],
+  /// The markers for times before and after noon, as they are written by `%p`.
+  pub am_pm: [&'static str; 2],
+  /// The names of the eras before and after the beginning of the common era (such as `BC` and
+  /// `AD`), in that order. Each era may have several names.
+  pub era_names: [&'static [&'static str]; 2],
+}
+
+impl Locale {
+  /// English month and weekday names (the default).
+  pub const ENGLISH: Self = Self {
+    month_names: [
+      "January", "February", "March", "April", "May", "June", "July", "August", "September",
+      "October", "November", "December",
+    ],
+    month_abbrs: [
+      "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekday_names: ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+    weekday_abbrs: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    am_pm: ["AM", "PM"],
+    era_names: [&["BC", "BCE", "B.C.", "B.C.E."], &["AD", "CE", "A.D.", "C.E."]],
+  };
+  /// French month and weekday names.
+  pub const FRENCH: Self = Self {
+    month_names: [
+      "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+      "octobre", "novembre", "décembre",
+    ],
+    month_abbrs: [
+      "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+      "déc.",
+    ],
+    weekday_names: ["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+    weekday_abbrs: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    am_pm: ["AM", "PM"],
+    era_names: [&["av. J.-C.", "avant Jésus-Christ"], &["ap. J.-C.", "après Jésus-Christ"]],
+  };
+  /// German month and weekday names.
+  pub const GERMAN: Self = Self {
+    month_names: [
+      "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+      "Oktober", "November", "Dezember",
+    ],
+    month_abbrs: [
+      "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+      "Dez.",
+    ],
+    weekday_names: [
+      "Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag",
+    ],
+    weekday_abbrs: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+    am_pm: ["AM", "PM"],
+    era_names: [&["v. Chr.", "v. u. Z."], &["n. Chr.", "u. Z."]],
+  };
+  /// Spanish month and weekday names.
+  pub const SPANISH: Self = Self {
+    month_names: [
+      "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+      "octubre", "noviembre", "diciembre",
+    ],
+    month_abbrs: [
+      "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekday_names: ["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+    weekday_abbrs: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+    am_pm: ["a. m.", "p. m."],
+    era_names: [&["a. C.", "a. e. c."], &["d. C.", "e. c."]],
+  };
+
+  /// Set the full names of the months, beginning with January.
+  pub const fn with_month_names(mut self, names: [&'static str; 12]) -> Self {
+    self.month_names = names;
+    self
+  }
+
+  /// Set the abbreviated names of the months, beginning with January.
+  pub const fn with_month_abbrs(mut self, abbrs: [&'static str; 12]) -> Self {
+    self.month_abbrs = abbrs;
+    self
+  }
+
+  /// Set the full names of the weekdays, beginning with Sunday.
+  pub const fn with_weekday_names(mut self, names: [&'static str; 7]) -> Self {
+    self.weekday_names = names;
+    self
+  }
+
+  /// Set the abbreviated names of the weekdays, beginning with Sunday.
+  pub const fn with_weekday_abbrs(mut self, abbrs: [&'static str; 7]) -> Self {
+    self.weekday_abbrs = abbrs;
+    self
+  }
+
+  /// Set the markers for times before and after noon.
+  pub const fn with_am_pm(mut self, am_pm: [&'static str; 2]) -> Self {
+    self.am_pm = am_pm;
+    self
+  }
+
+  /// Set the names of the eras before and after the beginning of the common era.
+  pub const fn with_era_names(mut self, era_names: [&'static [&'static str]; 2]) -> Self {
+    self.era_names = era_names;
+    self
+  }
+}
+
+impl Default for Locale {
+  fn default() -> Self {
+    Self::ENGLISH
+  }
+}
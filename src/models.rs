@@ -1,79 +1,317 @@
+#[cfg(feature = "std")]
+use std::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+#[cfg(feature = "std")]
+use std::time::UNIX_EPOCH;
+
 use crate::error::ErrorKind;
 use crate::ParseError;
+use crate::ParseOptions;
 use crate::ParseResult;
 
 /// A representation of a raw date.
+///
+/// With the `serde` feature, a date serializes as its `year`, `quarter`, `month`, and `day`, any
+/// of which may be null. Deserialization sends an error for a date that the parser could not have
+/// produced, such as one with no components.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "crate::serde::RawDateRepr"))]
 pub struct RawDate {
-  pub(crate) year: Option<i16>,
+  pub(crate) year: Option<i32>,
+  pub(crate) quarter: Option<u8>,
   pub(crate) month: Option<u8>,
   pub(crate) day: Option<u8>,
 }
 
 impl RawDate {
   fn new() -> Self {
-    Self { year: None, month: None, day: None }
+    Self { year: None, quarter: None, month: None, day: None }
   }
 
-  /// The calendar year.
+  /// The calendar year. Years are numbered astronomically, so year 0 is 1 BC, year -1 is 2 BC,
+  /// and so on.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if the year was not parsed, which is only possible when the parser allows partial
+  /// dates. Use [`RawDate::year_opt`] in that case.
   #[inline]
-  pub fn year(&self) -> i16 {
+  pub fn year(&self) -> i32 {
     self.year.unwrap()
   }
 
+  /// The quarter of the year, between 1 and 4, inclusive. If no quarter was parsed, this is
+  /// derived from the month.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if neither the quarter nor the month was parsed, which is only possible when the
+  /// parser allows partial dates. Use [`RawDate::quarter_opt`] in that case.
+  #[inline]
+  pub fn quarter(&self) -> u8 {
+    self.quarter_opt().unwrap()
+  }
+
   /// The calendar month, between 1 and 12, inclusive.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if the month was not parsed, which is only possible when the parser allows partial
+  /// dates. Use [`RawDate::month_opt`] in that case.
   #[inline]
   pub fn month(&self) -> u8 {
     self.month.unwrap()
   }
 
   /// The day of the month; between 1 and 31, inclusive.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if the day was not parsed, which is only possible when the parser allows partial
+  /// dates. Use [`RawDate::day_opt`] in that case.
   #[inline]
   pub fn day(&self) -> u8 {
     self.day.unwrap()
   }
 
-  pub(crate) fn assert_complete(&self, src: &str) -> ParseResult<()> {
-    if self.year.is_none() || self.month.is_none() || self.day.is_none() {
-      Err(ParseError { src: src.into(), index: None, kind: ErrorKind::IncompleteDate })?;
+  /// The calendar year, if one was parsed.
+  #[inline]
+  pub const fn year_opt(&self) -> Option<i32> {
+    self.year
+  }
+
+  /// The quarter of the year, between 1 and 4 inclusive, if a quarter or month was parsed.
+  #[inline]
+  pub const fn quarter_opt(&self) -> Option<u8> {
+    match (self.quarter, self.month) {
+      (Some(quarter), _) => Some(quarter),
+      (None, Some(month)) => Some(((month as u16 + 2) / 3) as u8),
+      (None, None) => None,
+    }
+  }
+
+  /// The calendar month, between 1 and 12 inclusive, if one was parsed.
+  #[inline]
+  pub const fn month_opt(&self) -> Option<u8> {
+    self.month
+  }
+
+  /// The day of the month, between 1 and 31 inclusive, if one was parsed.
+  #[inline]
+  pub const fn day_opt(&self) -> Option<u8> {
+    self.day
+  }
+
+  /// Which components of the date were present in the input.
+  ///
+  /// This is always [`DatePrecision::YearMonthDay`] unless the parser allows partial dates.
+  pub fn precision(&self) -> DatePrecision {
+    match (self.year, self.month, self.day) {
+      (Some(_), None, None) if self.quarter.is_some() => DatePrecision::YearQuarter,
+      (Some(_), None, None) => DatePrecision::Year,
+      (Some(_), Some(_), None) => DatePrecision::YearMonth,
+      (None, Some(_), Some(_)) => DatePrecision::MonthDay,
+      _ => DatePrecision::YearMonthDay,
+    }
+  }
+
+  /// The number of days between the Unix epoch (January 1, 1970) and this date, using the
+  /// proleptic Gregorian calendar. Dates before the epoch are negative.
+  ///
+  /// The day is not checked against the length of the month, so a date such as February 31 counts
+  /// on into March. The Unix timestamp methods on [`RawDateTime`] send `OutOfRange` instead.
+  ///
+  /// ## Panics
+  ///
+  /// Panics if the date is partial (see [`RawDate::precision`]).
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let date = Parser::new("%Y-%m-%d").parse("2012-04-21")?.date()?;
+  /// assert_eq!(date.days_since_epoch(), 15451);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn days_since_epoch(&self) -> i64 {
+    // Algorithm from Howard Hinnant's `days_from_civil`; years are shifted to begin in March so
+    // that the leap day falls at the end.
+    let (year, month, day) = (self.year() as i64, self.month() as i64, self.day() as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+  }
+
+  /// Send `OutOfRange` unless the month is between 1 and 12, and the day exists in that month.
+  pub(crate) fn assert_valid_day(&self) -> ParseResult<()> {
+    match days_in_month(self.year(), self.month()) {
+      Some(days) if (1..=days).contains(&self.day()) => Ok(()),
+      _ => Err(ErrorKind::OutOfRange.into()),
+    }
+  }
+
+  /// Use the given day (or the last day of the month, if that is earlier) if the day is missing,
+  /// along with the first month of the quarter if the month is also missing. Dates without a
+  /// year, or with neither a month nor a quarter, are left alone.
+  pub(crate) fn apply_default_day(&mut self, day: u8) {
+    if let (Some(year), None) = (self.year, self.day) {
+      if let (None, Some(quarter @ 1..=4)) = (self.month, self.quarter) {
+        self.month = Some(quarter * 3 - 2);
+      }
+      if let Some(month) = self.month {
+        self.day = Some(days_in_month(year, month).map_or(day, |days| day.min(days)));
+      }
+    }
+  }
+
+  pub(crate) fn assert_complete(&self, allow_partial: bool) -> ParseResult<()> {
+    if self.month.is_some_and(|m| !(1..=12).contains(&m))
+      || self.day.is_some_and(|d| !(1..=31).contains(&d))
+    {
+      return Err(ErrorKind::OutOfRange.into());
+    }
+    if let Some(quarter) = self.quarter {
+      // The quarter must be valid, and agree with the month if both are present.
+      if !(1..=4).contains(&quarter) || self.month.is_some_and(|m| (m + 2) / 3 != quarter) {
+        return Err(ErrorKind::OutOfRange.into());
+      }
+    }
+    match (self.year, self.month, self.day) {
+      (Some(_), Some(_), Some(_)) => Ok(()),
+      (Some(_), None, None) | (Some(_), Some(_), None) | (None, Some(_), Some(_))
+        if allow_partial =>
+        Ok(()),
+      _ => Err(ErrorKind::IncompleteDate.into()),
     }
-    Ok(())
   }
 }
 
+/// The number of days in the given month, or `None` if there is no such month.
+fn days_in_month(year: i32, month: u8) -> Option<u8> {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => Some(29),
+    2 => Some(28),
+    4 | 6 | 9 | 11 => Some(30),
+    1..=12 => Some(31),
+    _ => None,
+  }
+}
+
+/// The components present in a [`RawDate`].
+///
+/// Dates are always complete unless the parser was configured with
+/// [`Parser::allow_partial_dates`](crate::Parser::allow_partial_dates).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum DatePrecision {
+  /// Only the year is known (e.g. `2012`).
+  Year,
+  /// The year and quarter are known, but not the month or day (e.g. `2012-Q2`).
+  YearQuarter,
+  /// The year and month are known, but not the day (e.g. `April 2012`).
+  YearMonth,
+  /// The month and day are known, but not the year (e.g. `April 21`).
+  MonthDay,
+  /// The year, month, and day are all known.
+  YearMonthDay,
+}
+
 /// A representation of time.
+///
+/// With the `serde` feature, a time serializes as its `hour`, `minute`, `second`, `nanosecond`,
+/// `fraction_digits` (the number of digits in the fractional second), and `utc_offset` (in
+/// seconds), any of which may be null. Deserialization sends an error if any component is out of
+/// range, although leap seconds and the end of the day are accepted.
 #[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+  feature = "serde",
+  serde(into = "crate::serde::RawTimeRepr", try_from = "crate::serde::RawTimeRepr")
+)]
 pub struct RawTime {
-  pub(crate) hour: u8,
-  pub(crate) minute: u8,
-  pub(crate) second: u8,
-  pub(crate) nanosecond: u64,
+  pub(crate) hour: Option<u8>,
+  pub(crate) minute: Option<u8>,
+  pub(crate) second: Option<u8>,
+  pub(crate) nanosecond: Option<(u64, u8)>,
   pub(crate) utc_offset: Option<i32>,
 }
 
 impl RawTime {
   /// The hour; between 0 and 23, inclusive.
+  ///
+  /// This may also be 24 if [`Parser::allow_end_of_day`](crate::Parser::allow_end_of_day) was set;
+  /// see [`RawTime::is_end_of_day`].
   #[inline]
   pub const fn hour(&self) -> u8 {
-    self.hour
+    match self.hour {
+      Some(hour) => hour,
+      None => 0,
+    }
   }
 
   /// The minute; between 0 and 59, inclusive.
   #[inline]
   pub const fn minute(&self) -> u8 {
-    self.minute
+    match self.minute {
+      Some(minute) => minute,
+      None => 0,
+    }
   }
 
   /// The second; between 0 and 59, inclusive.
+  ///
+  /// This may also be 60 if [`Parser::allow_leap_seconds`](crate::Parser::allow_leap_seconds) was
+  /// set; see [`RawTime::is_leap_second`].
   #[inline]
   pub const fn second(&self) -> u8 {
-    self.second
+    match self.second {
+      Some(second) => second,
+      None => 0,
+    }
   }
 
-  /// The microsecond; between 0 and 999,999, inclusive.
+  /// The nanosecond; between 0 and 999,999,999, inclusive.
   #[inline]
   pub const fn nanosecond(&self) -> u64 {
-    self.nanosecond
+    match self.nanosecond {
+      Some((nanosecond, _)) => nanosecond,
+      None => 0,
+    }
+  }
+
+  /// The hour, if it was present in the input.
+  #[inline]
+  pub const fn hour_opt(&self) -> Option<u8> {
+    self.hour
+  }
+
+  /// The minute, if it was present in the input.
+  #[inline]
+  pub const fn minute_opt(&self) -> Option<u8> {
+    self.minute
+  }
+
+  /// The second, if it was present in the input.
+  #[inline]
+  pub const fn second_opt(&self) -> Option<u8> {
+    self.second
+  }
+
+  /// The nanosecond, if a fractional second was present in the input.
+  #[inline]
+  pub const fn nanosecond_opt(&self) -> Option<u64> {
+    match self.nanosecond {
+      Some((nanosecond, _)) => Some(nanosecond),
+      None => None,
+    }
   }
 
   /// The UTC offset, in seconds, if one was parsed.
@@ -81,12 +319,89 @@ impl RawTime {
   pub const fn utc_offset(&self) -> Option<i32> {
     self.utc_offset
   }
+
+  /// Whether this time is a leap second (that is, the second is 60, as in `23:59:60`).
+  ///
+  /// Leap seconds are only accepted if
+  /// [`Parser::allow_leap_seconds`](crate::Parser::allow_leap_seconds) was set.
+  #[inline]
+  pub const fn is_leap_second(&self) -> bool {
+    matches!(self.second, Some(60))
+  }
+
+  /// Whether this time is the end of the day (`24:00:00`), as distinct from midnight at the start
+  /// of the day.
+  ///
+  /// This is only accepted if [`Parser::allow_end_of_day`](crate::Parser::allow_end_of_day) was
+  /// set.
+  #[inline]
+  pub const fn is_end_of_day(&self) -> bool {
+    matches!(self.hour, Some(24))
+  }
+
+  /// Send `OutOfRange` if any component is out of range.
+  pub(crate) fn assert_in_range(&self, opts: &ParseOptions) -> ParseResult<()> {
+    let max_second = if opts.leap_seconds { 60 } else { 59 };
+    let end_of_day = opts.end_of_day
+      && self.hour() == 24
+      && (self.minute(), self.second(), self.nanosecond()) == (0, 0, 0);
+    match self.hour() <= 23 || end_of_day {
+      true if self.minute() <= 59 && self.second() <= max_second => Ok(()),
+      _ => Err(ErrorKind::OutOfRange.into()),
+    }
+  }
+
+  /// The most precise time component that was present in the input.
+  ///
+  /// This is `None` if no time components were parsed (for example, if the format only contained
+  /// a UTC offset).
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// use strptime::TimePrecision;
+  /// let time = Parser::new("%H:%M").parse("11:00")?.time()?;
+  /// assert_eq!(time.precision(), Some(TimePrecision::Minute));
+  /// let time = Parser::new("%H:%M:%S").parse("11:00:00")?.time()?;
+  /// assert_eq!(time.precision(), Some(TimePrecision::Second));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub const fn precision(&self) -> Option<TimePrecision> {
+    if let Some((_, digits)) = self.nanosecond {
+      return Some(TimePrecision::Fraction(digits));
+    }
+    match (self.hour, self.minute, self.second) {
+      (_, _, Some(_)) => Some(TimePrecision::Second),
+      (_, Some(_), None) => Some(TimePrecision::Minute),
+      (Some(_), None, None) => Some(TimePrecision::Hour),
+      (None, None, None) => None,
+    }
+  }
+}
+
+/// The most precise component present in a [`RawTime`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum TimePrecision {
+  /// The hour was the most precise component (e.g. `11 AM`).
+  Hour,
+  /// The minute was the most precise component (e.g. `11:00`).
+  Minute,
+  /// The second was the most precise component (e.g. `11:00:00`).
+  Second,
+  /// A fractional second was present, with the given number of digits (e.g. `3` for
+  /// `11:00:00.000`).
+  Fraction(u8),
 }
 
 /// A parsed date and time.
-#[derive(Debug, Clone)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct RawDateTime {
-  pub(crate) src: String,
   pub(crate) date: Option<RawDate>,
   pub(crate) time: Option<RawTime>,
 }
@@ -101,27 +416,159 @@ impl RawDateTime {
   /// handle `ParseResult` can do so here easily also. The only error this ever sends is
   /// `MissingDate`, and it's safe to send to `.ok()` if you want an `Option` instead.
   pub fn date(&self) -> ParseResult<RawDate> {
-    self.date.ok_or_else(|| ParseError::new(self.src.as_str(), ErrorKind::MissingDate))
+    self.date.ok_or_else(|| ErrorKind::MissingDate.into())
   }
 
   /// The time, if a time was parsed. If certain fields within the time were omitted, they will be
-  /// set to `0`.
+  /// set to `0`; use [`RawTime::precision`] or the `_opt` accessors to tell them apart.
   ///
   /// For convenience, this method sends `Result` rather than `Option` so that methods that want to
   /// handle `ParseResult` can do so here easily also. The only error this ever sends is
   /// `MissingTime`, and it's safe to send to `.ok()` if you want an `Option` instead.
   pub fn time(&self) -> ParseResult<RawTime> {
-    self.time.ok_or_else(|| ParseError::new(self.src.as_str(), ErrorKind::MissingTime))
+    self.time.ok_or_else(|| ErrorKind::MissingTime.into())
+  }
+
+  /// The number of seconds since the Unix epoch, applying the parsed UTC offset. A missing time
+  /// is treated as midnight.
+  ///
+  /// This sends `OutOfRange` if the day does not exist, such as February 30.
+  ///
+  /// As Unix time does not count leap seconds, a leap second is treated as the first second of the
+  /// following minute. The end of the day (`24:00`) is treated as midnight of the following day.
+  ///
+  /// This sends `MissingUtcOffset` if no UTC offset was parsed; use
+  /// [`RawDateTime::unix_timestamp_with_default_offset`] to supply one (such as `0` for UTC).
+  ///
+  /// ## Example
+  ///
+  /// ```
+  /// # fn main() -> strptime::ParseResult<()> {
+  /// use strptime::Parser;
+  /// let dt = Parser::new("%Y-%m-%dT%H:%M:%S%z").parse("2012-04-21T11:00:00-0400")?;
+  /// assert_eq!(dt.unix_timestamp()?, 1_335_020_400);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn unix_timestamp(&self) -> ParseResult<i64> {
+    self.unix_seconds(None)
+  }
+
+  /// The number of nanoseconds since the Unix epoch, applying the parsed UTC offset. A missing
+  /// time is treated as midnight.
+  ///
+  /// This sends `MissingUtcOffset` if no UTC offset was parsed; use
+  /// [`RawDateTime::unix_timestamp_nanos_with_default_offset`] to supply one.
+  pub fn unix_timestamp_nanos(&self) -> ParseResult<i128> {
+    self.unix_nanos(None)
   }
 
-  pub(crate) fn assert_complete(&self, src: &str) -> ParseResult<()> {
+  /// The number of seconds since the Unix epoch, using the parsed UTC offset if one is present
+  /// and `default_offset` (in seconds) otherwise.
+  pub fn unix_timestamp_with_default_offset(&self, default_offset: i32) -> ParseResult<i64> {
+    self.unix_seconds(Some(default_offset))
+  }
+
+  /// The number of nanoseconds since the Unix epoch, using the parsed UTC offset if one is
+  /// present and `default_offset` (in seconds) otherwise.
+  pub fn unix_timestamp_nanos_with_default_offset(
+    &self, default_offset: i32,
+  ) -> ParseResult<i128> {
+    self.unix_nanos(Some(default_offset))
+  }
+
+  /// Convert to a [`SystemTime`], using the parsed UTC offset if one is present and
+  /// `default_offset` (in seconds) otherwise. A missing time is treated as midnight.
+  ///
+  /// To require a parsed UTC offset, use `SystemTime::try_from` instead.
+  #[cfg(feature = "std")]
+  pub fn system_time_with_default_offset(&self, default_offset: i32) -> ParseResult<SystemTime> {
+    self.system_time(Some(default_offset))
+  }
+
+  #[cfg(feature = "std")]
+  fn system_time(&self, default_offset: Option<i32>) -> ParseResult<SystemTime> {
+    let nanos = self.unix_nanos(default_offset)?;
+    let out_of_range = || ParseError::from(ErrorKind::OutOfRange);
+    let seconds =
+      u64::try_from(nanos.unsigned_abs() / 1_000_000_000).map_err(|_| out_of_range())?;
+    let duration = Duration::new(seconds, (nanos.unsigned_abs() % 1_000_000_000) as u32);
+    match nanos >= 0 {
+      true => UNIX_EPOCH.checked_add(duration),
+      false => UNIX_EPOCH.checked_sub(duration),
+    }
+    .ok_or_else(out_of_range)
+  }
+
+  fn unix_seconds(&self, default_offset: Option<i32>) -> ParseResult<i64> {
+    let date = self.date()?;
+    date.assert_complete(false)?;
+    date.assert_valid_day()?;
+    let time = self.time.unwrap_or_default();
+    let offset = time.utc_offset.or(default_offset).ok_or(ErrorKind::MissingUtcOffset)?;
+    let seconds = time.hour() as i64 * 3600 + time.minute() as i64 * 60 + time.second() as i64;
+    Ok(date.days_since_epoch() * 86_400 + seconds - offset as i64)
+  }
+
+  fn unix_nanos(&self, default_offset: Option<i32>) -> ParseResult<i128> {
+    let seconds = self.unix_seconds(default_offset)? as i128;
+    Ok(seconds * 1_000_000_000 + self.time.unwrap_or_default().nanosecond() as i128)
+  }
+
+  /// Fill in any default components, and then send an error if the date is incomplete or if any
+  /// component is out of range.
+  pub(crate) fn finish(&mut self, src: &[u8], opts: &ParseOptions) -> ParseResult<()> {
+    if let (Some(date), Some(day)) = (&mut self.date, opts.default_day) {
+      date.apply_default_day(day);
+    }
     if let Some(date) = &self.date {
-      date.assert_complete(src)?;
+      date.assert_complete(opts.partial_dates).map_err(|e| e.with_src(src))?;
+    }
+    if let Some(time) = &self.time {
+      time.assert_in_range(opts).map_err(|e| e.with_src(src))?;
     }
     Ok(())
   }
 }
 
+#[cfg(feature = "std")]
+impl TryFrom<&RawDateTime> for SystemTime {
+  type Error = ParseError;
+
+  /// Convert to a [`SystemTime`]. A missing time is treated as midnight, but a UTC offset is
+  /// required.
+  fn try_from(value: &RawDateTime) -> ParseResult<Self> {
+    value.system_time(None)
+  }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<RawDateTime> for SystemTime {
+  type Error = ParseError;
+
+  /// Convert to a [`SystemTime`]. A missing time is treated as midnight, but a UTC offset is
+  /// required.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    value.system_time(None)
+  }
+}
+
+impl TryFrom<RawDateTime> for RawDate {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    value.date()
+  }
+}
+
+impl TryFrom<RawDateTime> for RawTime {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    value.time()
+  }
+}
+
 macro_rules! set_date {
   ($($fn_name:ident($arg:ident: $arg_type:ty)),*) => {
     impl RawDateTime {
@@ -134,7 +581,8 @@ macro_rules! set_date {
   }
 }
 set_date! (
-  set_year(year: i16),
+  set_year(year: i32),
+  set_quarter(quarter: u8),
   set_month(month: u8),
   set_day(day: u8)
 );
@@ -144,7 +592,7 @@ macro_rules! set_time {
     impl RawDateTime {
       $(pub(crate) fn $fn_name(&mut self, $arg: $arg_type) {
         let time = self.time.get_or_insert_with(RawTime::default);
-        time.$arg = $arg;
+        time.$arg = Some($arg);
       })*
     }
   }
@@ -153,11 +601,15 @@ macro_rules! set_time {
 set_time!(
   set_hour(hour: u8),
   set_minute(minute: u8),
-  set_second(second: u8),
-  set_nanosecond(nanosecond: u64)
+  set_second(second: u8)
 );
 
 impl RawDateTime {
+  pub(crate) fn set_nanosecond(&mut self, nanosecond: u64, digits: u8) {
+    let time = self.time.get_or_insert_with(RawTime::default);
+    time.nanosecond = Some((nanosecond, digits));
+  }
+
   pub(crate) fn set_utc_offset(&mut self, hhmm: i32) {
     let hours = hhmm / 100;
     let minutes = hhmm % 100;
@@ -1,89 +1,187 @@
-use std::iter::Peekable;
-use std::num::ParseIntError;
-use std::ops::Deref;
-use std::ops::DerefMut;
-use std::str::Chars;
-use std::str::FromStr;
+use core::iter;
+use core::iter::Peekable;
+use core::str;
 
 use crate::error::ErrorKind;
+use crate::error::Expected;
+use crate::fixed;
+use crate::numerals;
+use crate::FractionOverflow;
+use crate::Locale;
 use crate::ParseError;
 use crate::ParseOptions;
 use crate::ParseResult;
+use crate::Parser;
 use crate::RawDateTime;
 
 /// An object that parses one and exactly one date and time string, and is consumed.
 #[must_use]
 pub(crate) struct OnceParser<'a> {
   fmt: &'static str,
-  date_str: &'a str,
+  fixed_width: Option<usize>,
+  date_str: &'a [u8],
+  error_src: &'a [u8],
   opts: ParseOptions,
   partials: Partials,
 }
 
 impl<'a> OnceParser<'a> {
   #[inline]
-  pub(crate) fn new(fmt: &'static str, date_str: &'a str, opts: ParseOptions) -> Self {
-    Self { fmt, date_str, opts, partials: Partials::default() }
+  pub(crate) fn new(parser: &Parser, date_str: &'a [u8]) -> Self {
+    Self {
+      fmt: parser.fmt,
+      fixed_width: parser.fixed_width,
+      date_str,
+      error_src: date_str,
+      opts: parser.opts,
+      partials: Partials::default(),
+    }
+  }
+
+  /// Omit the input from any errors. This avoids copying the input into errors that the caller
+  /// intends to discard.
+  pub(crate) fn without_error_src(mut self) -> Self {
+    self.error_src = b"";
+    self
   }
 
-  pub(crate) fn parse(mut self) -> ParseResult<RawDateTime> {
-    let mut answer = RawDateTime { src: self.date_str.into(), date: None, time: None };
+  pub(crate) fn parse(self) -> ParseResult<RawDateTime> {
+    let (date_str, error_src) = (self.date_str, self.error_src);
+    let (answer, consumed) = self.parse_prefix()?;
+    match decode_char(&date_str[consumed..]) {
+      Some((ch, _)) => Err(
+        ParseError::new(error_src, ErrorKind::InputTooLong)
+          .at_index(consumed)
+          .expecting(Expected::End, Some(ch)),
+      ),
+      None => Ok(answer),
+    }
+  }
+
+  /// Parse a date and time from the beginning of the input, and return it along with the number
+  /// of bytes consumed. Any remaining input is ignored.
+  pub(crate) fn parse_prefix(mut self) -> ParseResult<(RawDateTime, usize)> {
+    if let Some(width) = self.fixed_width {
+      if let Some(mut answer) = fixed::parse(self.fmt, self.date_str) {
+        // If a value is out of range, fall through so that the error records where it is.
+        if answer.finish(self.error_src, &self.opts).is_ok() {
+          return Ok((answer, width));
+        }
+      }
+    }
+
+    let mut answer = RawDateTime { date: None, time: None };
 
     // Begin iterating over the format string, and incrementally "chew" characters from the
     // beginning of the date string.
-    let mut input = Input::new(self.date_str);
+    let mut input = Input::new(self.fmt, self.date_str, self.error_src, self.opts.unicode_digits);
     let mut flag = false;
     let mut padding = None;
+    let mut padded = false;
     let mut nano_digits = None;
-    for ch in self.fmt.chars() {
+    let mut alternative = false;
+    let max_hour = if self.opts.end_of_day { 24 } else { 23 };
+    let max_second = if self.opts.leap_seconds { 60 } else { 59 };
+    let mut hour_at = None;
+    for (ix, ch) in self.fmt.char_indices() {
       if ch != 'f' && nano_digits.is_some() {
         input.fail(ErrorKind::InvalidFormat)?;
       }
       match flag {
         true => {
           flag = false;
+          let span = input.span();
           match ch {
+            // Date: Era
+            'C' if alternative =>
+              self.partials.bce = Some((input.parse_era(self.opts.locale)?, span)),
+            'y' if alternative => answer.set_year(input.parse_int::<i32>(0, Some('-'))?),
+            _ if alternative => input.fail(ErrorKind::InvalidFormat)?,
             // Date: Year
-            'Y' => answer.set_year(input.parse_int::<i16>(4, padding)?),
-            'C' => self.partials.century = Some(input.parse_int::<i16>(2, padding)?),
-            'y' => self.partials.year_modulo = Some(input.parse_int::<i16>(2, padding)?),
+            'Y' => answer.set_year(input.parse_year(padding, self.opts.year_digits)?),
+            'C' => self.partials.century = Some((input.parse_int::<i32>(2, padding)?, span)),
+            'y' => self.partials.year_modulo = Some((input.parse_int::<i32>(2, padding)?, span)),
+            // Date: Quarter
+            'q' => {
+              // The quarter must agree with the month, if that came first.
+              let (min, max) = match answer.date.and_then(|d| d.month) {
+                Some(month @ 1..=12) => ((month + 2) / 3, (month + 2) / 3),
+                _ => (1, 4),
+              };
+              answer.set_quarter(input.parse_ranged(1, padding, min, max)?);
+            },
             // Date: Month
-            'm' => answer.set_month(input.parse_int::<u8>(2, padding)?),
-            'b' | 'h' => answer.set_month(input.parse_month_abbr()?),
-            'B' => answer.set_month(input.parse_month()?),
+            'm' => {
+              // The month must agree with the quarter, if that came first.
+              let (min, max) = match answer.date.and_then(|d| d.quarter) {
+                Some(quarter @ 1..=4) => (quarter * 3 - 2, quarter * 3),
+                _ => (1, 12),
+              };
+              answer.set_month(input.parse_ranged(2, padding, min, max)?);
+            },
+            'b' | 'h' | 'B' => {
+              let month = input.parse_month(&self.opts, ch == 'B')?;
+              // The month must agree with the quarter, if that came first.
+              if let Some(quarter @ 1..=4) = answer.date.and_then(|d| d.quarter) {
+                let (min, max) = (quarter * 3 - 2, quarter * 3);
+                if !(min..=max).contains(&month) {
+                  let expected = Expected::Range { min: min.into(), max: max.into() };
+                  return Err(input.err_at(span, ErrorKind::OutOfRange).expecting(expected, None));
+                }
+              }
+              answer.set_month(month);
+            },
             // Date: Day
-            'd' => answer.set_day(input.parse_int::<u8>(2, padding)?),
-            'e' => answer.set_day(input.parse_int::<u8>(2, Some(padding.unwrap_or(' ')))?),
+            'd' => answer.set_day(input.parse_ranged(2, padding, 1, 31)?),
+            'e' => answer.set_day(input.parse_ranged(2, Some(padding.unwrap_or(' ')), 1, 31)?),
             // Date: Weekday
             //
             // Currently this is just thrown away once validation is done, but once %U/%W are
             // supported, this could be used to parse a full date.
-            'a' => drop(input.parse_weekday_abbr()?),
-            'A' => drop(input.parse_weekday()?),
+            'a' => drop(input.parse_weekday(&self.opts, false)?),
+            'A' => drop(input.parse_weekday(&self.opts, true)?),
             // Time: Hour
-            'H' => answer.set_hour(input.parse_int::<u8>(2, padding)?),
-            'k' => answer.set_hour(input.parse_int::<u8>(2, Some(padding.unwrap_or(' ')))?),
-            'I' => self.partials.hour_12 = Some(input.parse_int::<u8>(2, padding)?),
-            'p' => self.partials.pm = Some(input.parse_am_pm_upper()?),
-            'P' => self.partials.pm = Some(input.parse_am_pm_lower()?),
+            'H' => {
+              answer.set_hour(input.parse_ranged(2, padding, 0, max_hour)?);
+              hour_at = Some(span);
+            },
+            'k' => {
+              let padding = Some(padding.unwrap_or(' '));
+              answer.set_hour(input.parse_ranged(2, padding, 0, max_hour)?);
+              hour_at = Some(span);
+            },
+            'I' => self.partials.hour_12 = Some((input.parse_ranged(2, padding, 1, 12)?, span)),
+            'p' => self.partials.pm = Some((input.parse_am_pm(self.opts.locale, false)?, span)),
+            'P' => self.partials.pm = Some((input.parse_am_pm(self.opts.locale, true)?, span)),
             // Time: Minute
-            'M' => answer.set_minute(input.parse_int::<u8>(2, padding)?),
+            'M' => answer.set_minute(input.parse_ranged(2, padding, 0, 59)?),
             // Time: Second
-            'S' => answer.set_second(input.parse_int::<u8>(2, padding)?),
+            'S' => answer.set_second(input.parse_ranged(2, padding, 0, max_second)?),
             // Time: Nanosecond
             'f' => match nano_digits.take() {
-              Some(3) => answer.set_nanosecond(input.parse_int::<u64>(3, Some('0'))? * 1_000_000),
-              Some(6) => answer.set_nanosecond(input.parse_int::<u64>(6, Some('0'))? * 1000),
-              _ => answer.set_nanosecond(input.parse_int::<u64>(9, Some('0'))?),
+              _ if padded && padding == Some('-') => {
+                let (nanosecond, digits) = input.parse_fraction(self.opts.fraction_overflow)?;
+                answer.set_nanosecond(nanosecond, digits);
+              },
+              Some(3) =>
+                answer.set_nanosecond(input.parse_int::<u64>(3, Some('0'))? * 1_000_000, 3),
+              Some(6) => answer.set_nanosecond(input.parse_int::<u64>(6, Some('0'))? * 1000, 6),
+              _ => answer.set_nanosecond(input.parse_int::<u64>(9, Some('0'))?, 9),
             },
             // Time Zone
             'z' => {
               let sign = input.parse_sign()?;
-              answer.set_utc_offset(input.parse_int::<i32>(4, Some('0'))? * sign);
+              let hhmm = input.parse_int::<i32>(4, Some('0'))?;
+              if hhmm / 100 > 23 || hhmm % 100 > 59 {
+                let err = input.err_at(span, ErrorKind::OutOfRange);
+                return Err(err.expecting(Expected::UtcOffset, None));
+              }
+              answer.set_utc_offset(hhmm * sign);
             },
             // Padding change modifiers.
             '-' | '0' | ' ' => {
               padding = Some(ch);
+              padded = true;
               flag = true;
             },
             // Prefix modifiers
@@ -96,98 +194,132 @@ impl<'a> OnceParser<'a> {
               nano_digits = ch.to_digit(10);
               flag = true;
             },
+            // Alternative representation modifier
+            'E' => {
+              alternative = true;
+              flag = true;
+            },
             _ => input.fail(ErrorKind::InvalidFormat)?,
           }
+          // Padding carries over to later specifiers, but other modifiers (and the choice of a
+          // variable-length `%-f`) apply only to the specifier that they precede.
+          if !flag {
+            padded = false;
+            alternative = false;
+          }
         },
-        false => match ch {
-          '%' => flag = true,
-          ch => input.expect_char(ch)?,
+        false => {
+          input.format_index = ix;
+          match ch {
+            '%' => flag = true,
+            ch => input.expect_char(ch)?,
+          }
         },
       };
     }
 
     // Process partials.
-    if let Some(year) = self.partials.year(self.date_str, &self.opts)? {
+    if let Some(year) = self.partials.year(&input, &self.opts)? {
+      answer.set_year(year);
+    }
+    if let Some(year) = self.partials.era_year(answer.date.and_then(|d| d.year), &input)? {
       answer.set_year(year);
     }
-    if let Some(hour) = self.partials.hour(self.date_str)? {
+    if let Some(hour) = self.partials.hour(&input)? {
       answer.set_hour(hour);
     }
 
-    // Assert that our answer is complete.
-    answer.assert_complete(self.date_str)?;
-    input.assert_consumed()?;
-    Ok(answer)
+    // The end of the day must be exactly `24:00:00`.
+    if let (Some(span), Some(time)) = (hour_at, answer.time) {
+      if time.is_end_of_day() && (time.minute(), time.second(), time.nanosecond()) != (0, 0, 0) {
+        let expected = Expected::Range { min: 0, max: 23 };
+        return Err(input.err_at(span, ErrorKind::OutOfRange).expecting(expected, None));
+      }
+    }
+
+    // Fill in defaults, and assert that our answer is complete and in range.
+    answer.finish(self.error_src, &self.opts)?;
+    Ok((answer, input.index()))
   }
 }
 
 /// A wrapper around the original input, capable of easily handling errors.
+///
+/// The input is processed as bytes, so that callers with non-UTF-8 buffers need not validate them
+/// first. Characters are decoded from UTF-8 only as needed, and all indices are byte offsets.
+///
+/// Errors are attributed to the specifier or literal character at `format_index` in `fmt`.
 struct Input<'a> {
-  src: &'a str,
-  chars: Peekable<Chars<'a>>,
+  src: &'a [u8],
+  rest: &'a [u8],
+  error_src: &'a [u8],
+  unicode_digits: bool,
+  fmt: &'static str,
+  format_index: usize,
 }
 
 impl<'a> Input<'a> {
-  fn new(date_str: &'a str) -> Self {
-    Self { src: date_str, chars: date_str.chars().peekable() }
+  fn new(
+    fmt: &'static str, date_str: &'a [u8], error_src: &'a [u8], unicode_digits: bool,
+  ) -> Self {
+    Self { src: date_str, rest: date_str, error_src, unicode_digits, fmt, format_index: 0 }
   }
-}
-
-impl<'a> Deref for Input<'a> {
-  type Target = Peekable<Chars<'a>>;
 
-  fn deref(&self) -> &Self::Target {
-    &self.chars
+  /// Peek at the next character without consuming it.
+  fn peek(&self) -> Option<char> {
+    decode_char(self.rest).map(|(ch, _)| ch)
   }
-}
 
-impl<'a> DerefMut for Input<'a> {
-  fn deref_mut(&mut self) -> &mut Self::Target {
-    &mut self.chars
+  /// Consume and return the next character.
+  fn next(&mut self) -> Option<char> {
+    let (ch, len) = decode_char(self.rest)?;
+    self.rest = &self.rest[len..];
+    Some(ch)
   }
-}
 
-impl<'a> Input<'a> {
-  /// Pop characters off of the beginning and yield them.
-  fn pop_front(&mut self, n: usize) -> String {
-    let mut s = String::with_capacity(n);
-    while s.len() < n {
-      let Some(ch) = self.next() else { return s };
-      s.push(ch);
+  /// Pop up to `n` characters off of the beginning and yield them.
+  fn pop_front(&mut self, n: usize) -> &'a [u8] {
+    let start = self.rest;
+    for _ in 0..n {
+      if self.next().is_none() {
+        break;
+      }
     }
-    s
+    &start[..start.len() - self.rest.len()]
   }
 
   /// Pop characters off of the beginning while they satisfy the given condition.
-  fn pop_front_while(&mut self, pred: impl Fn(&char) -> bool) -> String {
-    let mut s = String::new();
-    while self.peek().map(&pred).unwrap_or_default() {
-      s.push(self.next().unwrap());
+  fn pop_front_while(&mut self, pred: impl Fn(&char) -> bool) -> &'a [u8] {
+    let start = self.rest;
+    while let Some((ch, len)) = decode_char(self.rest) {
+      if !pred(&ch) {
+        break;
+      }
+      self.rest = &self.rest[len..];
     }
-    s
+    &start[..start.len() - self.rest.len()]
   }
 
   /// Parse a static character.
   fn expect_char(&mut self, ch: char) -> ParseResult<()> {
-    self.expect_chars(&[ch])?;
+    self.expect_chars(&[ch], Expected::Char(ch))?;
     Ok(())
   }
 
   /// Parse one of an option of static characters.
-  fn expect_chars(&mut self, chars: &[char]) -> ParseResult<char> {
-    self.peek().cloned().ok_or_else(|| self.err(ErrorKind::InputTooShort)).and_then(
-      |c| match chars.contains(&c) {
-        true => {
-          self.next();
-          Ok(c)
-        },
-        false => self.fail(ErrorKind::Unexpected),
+  fn expect_chars(&mut self, chars: &[char], expected: Expected) -> ParseResult<char> {
+    match self.peek() {
+      Some(c) if chars.contains(&c) => {
+        self.next();
+        Ok(c)
       },
-    )
+      Some(_) => self.fail_expecting(expected),
+      None => Err(self.err(ErrorKind::InputTooShort).expecting(expected, None)),
+    }
   }
 
   fn parse_sign(&mut self) -> ParseResult<i32> {
-    let sign_char = self.expect_chars(&['+', '-'])?;
+    let sign_char = self.expect_chars(&['+', '-'], Expected::Sign)?;
     Ok(match sign_char {
       '+' => 1,
       '-' => -1,
@@ -196,167 +328,433 @@ impl<'a> Input<'a> {
   }
 
   /// Parse an integer, usually with the given number of digits, from the input.
-  fn parse_int<I: FromStr<Err = ParseIntError>>(
+  fn parse_int<I: TryFrom<u64>>(
     &mut self, digits: usize, padding: Option<char>,
   ) -> ParseResult<I> {
-    let e = self.err(ErrorKind::Unexpected);
-    match padding {
-      Some('-') => self.pop_front_while(|c| c.is_numeric()).parse::<I>().map_err(|_| e),
-      Some(' ') => self.pop_front(digits).trim_start().parse::<I>().map_err(|_| e),
-      Some('0') | None => self.pop_front(digits).parse::<I>().map_err(|_| e),
+    let start = self.rest;
+    let unicode = self.unicode_digits;
+    let int_bytes = match padding {
+      Some('-') => self.pop_front_while(|c| numerals::digit_value(*c, unicode).is_some()),
+      Some(' ') => {
+        let popped = self.pop_front(digits);
+        &popped[popped.iter().take_while(|b| **b == b' ').count()..]
+      },
+      Some('0') | None => self.pop_front(digits),
       _ => unreachable!("Invalid padding"),
+    };
+    let int = self.int_value(int_bytes).and_then(|int| out_of_range(I::try_from(int)));
+    int.or_else(|kind| {
+      // Report the error at the beginning of the integer.
+      self.rest = start;
+      let expected = match padding {
+        Some('-') => Expected::Digits { min: 1, max: usize::MAX },
+        Some(' ') => Expected::Digits { min: 1, max: digits },
+        _ => Expected::Digits { min: digits, max: digits },
+      };
+      self.fail_digits(kind, expected, padding == Some(' '))
+    })
+  }
+
+  /// Parse an integer as [`Input::parse_int`] does, and send `OutOfRange` (reported at the
+  /// beginning of the integer) unless it is between `min` and `max`.
+  fn parse_ranged(
+    &mut self, digits: usize, padding: Option<char>, min: u8, max: u8,
+  ) -> ParseResult<u8> {
+    let start = self.rest;
+    let int = self.parse_int::<u8>(digits, padding)?;
+    if !(min..=max).contains(&int) {
+      self.rest = start;
+      let expected = Expected::Range { min: min.into(), max: max.into() };
+      return Err(self.err(ErrorKind::OutOfRange).expecting(expected, None));
     }
+    Ok(int)
   }
 
-  /// Parse a month abbreviation (always three letters).
-  fn parse_month_abbr(&mut self) -> ParseResult<u8> {
-    let abbr = self.pop_front(3).to_lowercase();
-    Ok(match abbr.as_str() {
-      "jan" => 1,
-      "feb" => 2,
-      "mar" => 3,
-      "apr" => 4,
-      "may" => 5,
-      "jun" => 6,
-      "jul" => 7,
-      "aug" => 8,
-      "sep" => 9,
-      "oct" => 10,
-      "nov" => 11,
-      "dec" => 12,
-      _ => self.fail(ErrorKind::Unexpected)?,
-    })
+  /// Parse a year from the input: an optional sign, followed by between `min` and `max` digits
+  /// (or any number of digits, with the `-` padding modifier).
+  fn parse_year(&mut self, padding: Option<char>, (min, max): (usize, usize)) -> ParseResult<i32> {
+    let start = self.rest;
+    let (min, max) = match padding {
+      Some('-') => (1, usize::MAX),
+      Some(' ') => {
+        // Leading spaces count toward the width.
+        let spaces = self.pop_front_while(|c| *c == ' ').len();
+        (min.saturating_sub(spaces).max(1), max.saturating_sub(spaces))
+      },
+      _ => (min, max),
+    };
+    let negative = match self.peek() {
+      Some(sign @ ('+' | '-')) => {
+        self.next();
+        sign == '-'
+      },
+      _ => false,
+    };
+    let (digits_start, mut count) = (self.rest, 0);
+    while count < max
+      && self.peek().is_some_and(|c| numerals::digit_value(c, self.unicode_digits).is_some())
+    {
+      self.next();
+      count += 1;
+    }
+    let expected = Expected::Digits { min, max };
+    let year = match count >= min {
+      true => self
+        .int_value(&digits_start[..digits_start.len() - self.rest.len()])
+        .and_then(|year| out_of_range(i32::try_from(year))),
+      false => Err(ErrorKind::Unexpected),
+    };
+    match year {
+      Ok(year) if negative => Ok(-year),
+      Ok(year) => Ok(year),
+      Err(kind) => {
+        // Report the error at the beginning of the year.
+        self.rest = start;
+        self.fail_digits(kind, expected, true)
+      },
+    }
   }
 
-  /// Parse a full month name. This succeeds if at least the three-letter abbreviation is present,
-  /// and continues to parse until the month name is completed or it ceases to find a match
-  /// (therefore matching "Sept", for example).
-  fn parse_month(&mut self) -> ParseResult<u8> {
-    let month = self.parse_month_abbr()?;
-    self.trim_front_seq(match month {
-      1 => "uary",
-      2 => "ruary",
-      3 => "ch",
-      4 => "il",
-      6 => "e",
-      7 => "y",
-      8 => "ust",
-      9 => "tember",
-      10 => "ober",
-      11 | 12 => "ember",
-      _ => "",
-    });
-    Ok(month)
-  }
-
-  /// Parse a weekday abbreviation (always three letters).
-  fn parse_weekday_abbr(&mut self) -> ParseResult<u8> {
-    let abbr = self.pop_front(3).to_lowercase();
-    Ok(match abbr.as_str() {
-      "sun" => 0,
-      "mon" => 1,
-      "tue" => 2,
-      "wed" => 3,
-      "thu" => 4,
-      "fri" => 5,
-      "sat" => 6,
-      _ => self.fail(ErrorKind::Unexpected)?,
-    })
+  /// Return the value of the given digits. This sends `Unexpected` if there are no digits or any
+  /// character is not a digit, and `OutOfRange` if the value overflows.
+  fn int_value(&self, mut int_bytes: &[u8]) -> Result<u64, ErrorKind> {
+    if int_bytes.is_empty() {
+      return Err(ErrorKind::Unexpected);
+    }
+    let mut int = 0u64;
+    while let Some((ch, len)) = decode_char(int_bytes) {
+      let digit = numerals::digit_value(ch, self.unicode_digits).ok_or(ErrorKind::Unexpected)?;
+      int = out_of_range(int.checked_mul(10).and_then(|i| i.checked_add(digit.into())).ok_or(()))?;
+      int_bytes = &int_bytes[len..];
+    }
+    Ok(int)
   }
 
-  fn parse_weekday(&mut self) -> ParseResult<u8> {
-    let weekday = self.parse_weekday_abbr()?;
-    self.trim_front_seq(match weekday {
-      0 | 1 | 5 => "day",
-      2 => "sday",
-      3 => "nesday",
-      4 => "rsday",
-      6 => "urday",
-      _ => "",
-    });
-    Ok(weekday)
-  }
-
-  fn parse_am_pm_lower(&mut self) -> ParseResult<u8> {
-    let value = match self.peek() {
-      Some('a') => 0,
-      Some('p') => 12,
-      _ => self.fail(ErrorKind::Unexpected)?,
+  /// Fail with an error of the given kind, which (unless the value was merely out of range)
+  /// expected digits. The character found is the first that is not a digit, after any leading
+  /// spaces or sign if `prefixed` is set.
+  fn fail_digits<T>(&self, kind: ErrorKind, expected: Expected, prefixed: bool) -> ParseResult<T> {
+    if kind == ErrorKind::OutOfRange {
+      return self.fail(kind);
+    }
+    let mut rest = self.rest;
+    if prefixed {
+      while let Some((' ', len)) = decode_char(rest) {
+        rest = &rest[len..];
+      }
+      if let Some(('+' | '-', len)) = decode_char(rest) {
+        rest = &rest[len..];
+      }
+    }
+    let found = loop {
+      match decode_char(rest) {
+        Some((ch, len)) if numerals::digit_value(ch, self.unicode_digits).is_some() =>
+          rest = &rest[len..],
+        other => break other.map(|(ch, _)| ch),
+      }
     };
-    self.pop_front(1);
-    self.expect_char('m')?;
-    Ok(value)
+    Err(self.err(kind).expecting(expected, found))
   }
 
-  fn parse_am_pm_upper(&mut self) -> ParseResult<u8> {
-    let value = match self.peek() {
-      Some('A') => 0,
-      Some('P') => 12,
-      _ => self.fail(ErrorKind::Unexpected)?,
-    };
-    self.pop_front(1);
-    self.expect_char('M')?;
-    Ok(value)
+  /// Parse a fraction of a second of between one and nine digits, and return it in nanoseconds
+  /// along with the number of digits present. Any further digits are handled according to
+  /// `overflow`.
+  fn parse_fraction(&mut self, overflow: FractionOverflow) -> ParseResult<(u64, u8)> {
+    let (mut nanosecond, mut digits, mut first_dropped) = (0, 0, None);
+    while let Some(digit) = self.peek().and_then(|c| numerals::digit_value(c, self.unicode_digits))
+    {
+      match (digits, overflow) {
+        (0..=8, _) => {
+          nanosecond = nanosecond * 10 + u64::from(digit);
+          digits += 1;
+        },
+        (_, FractionOverflow::Error) => self.fail_expecting(DIGITS_1_TO_9)?,
+        (..) => drop(first_dropped.get_or_insert(digit)),
+      }
+      self.next();
+    }
+    if digits == 0 {
+      self.fail_expecting(DIGITS_1_TO_9)?;
+    }
+    if overflow == FractionOverflow::Round && first_dropped >= Some(5) {
+      // Saturate rather than carry into the seconds.
+      nanosecond = (nanosecond + 1).min(999_999_999);
+    }
+    Ok((nanosecond * 10u64.pow(9 - digits), digits as u8))
+  }
+
+  /// Parse an era name from the locale, and return whether it is before the common era.
+  fn parse_era(&mut self, locale: &Locale) -> ParseResult<bool> {
+    let mut best: Option<(bool, usize)> = None;
+    for (bce, names) in [(true, locale.era_names[0]), (false, locale.era_names[1])] {
+      for name in names {
+        let (chars, bytes) = self.match_prefix(name, fold_case, fold_case);
+        if chars > 0 && chars == name.chars().count() && best.map_or(true, |(_, len)| bytes > len)
+        {
+          best = Some((bce, bytes));
+        }
+      }
+    }
+    match best {
+      Some((bce, len)) => {
+        self.rest = &self.rest[len..];
+        Ok(bce)
+      },
+      None => self.fail_expecting(Expected::EraName),
+    }
   }
 
-  /// Trim all or part of the given sequence, case-insensitive. Stop at the first non-match found.
-  fn trim_front_seq(&mut self, seq: &'static str) {
-    for seq_char in seq.to_lowercase().chars() {
-      if self.expect_char(seq_char).is_ok() {
-        continue;
+  /// Parse a month name, and return the month number (January = 1).
+  ///
+  /// Abbreviations from the locale are always accepted. If `full` is set, full names are also
+  /// accepted, as is any prefix of a full name at least as long as its abbreviation (therefore
+  /// matching "Sept", for example). Custom month names are accepted in either case.
+  fn parse_month(&mut self, opts: &ParseOptions, full: bool) -> ParseResult<u8> {
+    let names: &[&str] = if full { &opts.locale.month_names } else { &[] };
+    let expected = Expected::MonthName;
+    self.parse_name(&opts.locale.month_abbrs, names, 1, opts.month_names, expected)
+  }
+
+  /// Parse a weekday name, and return the weekday number (Sunday = 0).
+  ///
+  /// See [`Input::parse_month`] for which names are accepted.
+  fn parse_weekday(&mut self, opts: &ParseOptions, full: bool) -> ParseResult<u8> {
+    let names: &[&str] = if full { &opts.locale.weekday_names } else { &[] };
+    let expected = Expected::WeekdayName;
+    self.parse_name(&opts.locale.weekday_abbrs, names, 0, opts.weekday_names, expected)
+  }
+
+  /// Parse the locale's marker for before or after noon, and return the hour offset (0 or 12).
+  ///
+  /// The marker must match exactly, or in lowercase if `lower` is set.
+  fn parse_am_pm(&mut self, locale: &Locale, lower: bool) -> ParseResult<u8> {
+    for (marker, value) in locale.am_pm.iter().zip([0, 12]) {
+      let (chars, bytes) = match lower {
+        true => self.match_prefix(marker, char::to_lowercase, iter::once),
+        false => self.match_prefix(marker, iter::once, iter::once),
       };
-      if self.expect_char(seq_char.to_ascii_uppercase()).is_ok() {
-        continue;
+      if chars == marker.chars().count() {
+        self.rest = &self.rest[bytes..];
+        return Ok(value);
       }
-      break;
     }
+    self.fail_expecting(Expected::AmPm)
   }
 
-  /// Assert that no input remains, and create a parse error if it does.
-  fn assert_consumed(&mut self) -> ParseResult<()> {
-    if self.peek().is_some() {
-      self.fail(ErrorKind::InputTooLong)?;
+  /// Parse the longest name matching the beginning of the input, ignoring case, and return its
+  /// value.
+  ///
+  /// Each abbreviation has the value of its position in the table plus `first`. If full names are
+  /// provided, any prefix of a full name at least as long as the corresponding abbreviation
+  /// (ignoring a trailing `.`) is also accepted. Custom names must match in their entirety, and
+  /// take precedence over the locale in the event of a tie.
+  fn parse_name(
+    &mut self, abbrs: &[&str], names: &[&str], first: u8, custom: &[(&str, u8)],
+    expected: Expected,
+  ) -> ParseResult<u8> {
+    let mut best: Option<(u8, usize)> = None;
+    let mut consider = |value: u8, len: Option<usize>| match (len, best) {
+      (Some(len), Some((_, best_len))) if len <= best_len => {},
+      (Some(len), _) => best = Some((value, len)),
+      (None, _) => {},
+    };
+    for (name, value) in custom {
+      let (chars, bytes) = self.match_prefix(name, fold_case, fold_case);
+      consider(*value, (chars > 0 && chars == name.chars().count()).then_some(bytes));
     }
-    Ok(())
+    for (ix, abbr) in abbrs.iter().enumerate() {
+      let (chars, bytes) = self.match_prefix(abbr, fold_case, fold_case);
+      let mut len = (chars == abbr.chars().count()).then_some(bytes);
+      if let Some(name) = names.get(ix) {
+        let (chars, bytes) = self.match_prefix(name, fold_case, fold_case);
+        if chars > 0 && chars >= abbr.trim_end_matches('.').chars().count() {
+          len = len.max(Some(bytes));
+        }
+      }
+      consider(ix as u8 + first, len);
+    }
+    match best {
+      Some((value, len)) => {
+        self.rest = &self.rest[len..];
+        Ok(value)
+      },
+      None => self.fail_expecting(expected),
+    }
+  }
+
+  /// Match as much of the given name as possible against the beginning of the input, and return
+  /// the number of characters and bytes matched. Nothing is consumed.
+  ///
+  /// Each character of the name and of the input is mapped to a sequence of characters (such as
+  /// its case folding) before being compared, and the two sequences are compared as streams. A
+  /// match therefore only ends where both a name character and an input character end, which
+  /// allows a single character to match several (such as `ß` and `SS`).
+  fn match_prefix<N, I>(
+    &self, name: &str, map_name: impl Fn(char) -> N, map_input: impl Fn(char) -> I,
+  ) -> (usize, usize)
+  where
+    N: Iterator<Item = char>,
+    I: Iterator<Item = char>, {
+    let (mut name_chars, mut rest) = (name.chars(), self.rest);
+    let (mut name_mapped, mut input_mapped) = (None::<Peekable<N>>, None::<Peekable<I>>);
+    let (mut count, mut matched) = (0, (0, 0));
+    loop {
+      let name_done = name_mapped.as_mut().map_or(true, |n| n.peek().is_none());
+      let input_done = input_mapped.as_mut().map_or(true, |i| i.peek().is_none());
+      if name_done && input_done {
+        matched = (count, self.rest.len() - rest.len());
+      }
+      if name_done {
+        let Some(ch) = name_chars.next() else { break };
+        name_mapped = Some(map_name(ch).peekable());
+        count += 1;
+      }
+      if input_done {
+        let Some((ch, len)) = decode_char(rest) else { break };
+        input_mapped = Some(map_input(ch).peekable());
+        rest = &rest[len..];
+      }
+      if name_mapped.as_mut().and_then(Iterator::next)
+        != input_mapped.as_mut().and_then(Iterator::next)
+      {
+        break;
+      }
+    }
+    matched
+  }
+
+  /// The index of the next byte to be consumed.
+  fn index(&self) -> usize {
+    self.src.len() - self.rest.len()
+  }
+
+  /// The current position in the input and the format string.
+  fn span(&self) -> Span {
+    Span { index: self.index(), format_index: self.format_index }
   }
 
-  /// Generate a parse error.
+  /// Generate a parse error at the current index, attributed to the current part of the format.
   fn err(&self, kind: ErrorKind) -> ParseError {
-    ParseError::new(self.src, kind)
-      .at_index(self.src.len() - self.chars.clone().collect::<String>().len())
+    self.err_at(self.span(), kind)
+  }
+
+  /// Generate a parse error at the given position.
+  fn err_at(&self, span: Span, kind: ErrorKind) -> ParseError {
+    let ix = span.format_index;
+    let specifier = self.fmt[ix..].starts_with('%').then(|| directive(self.fmt, ix));
+    ParseError::new(self.error_src, kind).at_index(span.index).in_format(ix, specifier)
   }
 
   fn fail<T>(&self, kind: ErrorKind) -> ParseResult<T> {
     Err(self.err(kind))
   }
+
+  /// Fail because the input did not contain what was expected at the current index.
+  fn fail_expecting<T>(&self, expected: Expected) -> ParseResult<T> {
+    Err(self.err(ErrorKind::Unexpected).expecting(expected, self.peek()))
+  }
+}
+
+/// The expectation for a variable-length fraction of a second (`%-f`).
+const DIGITS_1_TO_9: Expected = Expected::Digits { min: 1, max: 9 };
+
+/// The position of a field: its index in the input, and the index of its specifier in the format
+/// string.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+  index: usize,
+  format_index: usize,
 }
 
+/// Fields that must be combined with others once parsing is done, each with where it was parsed
+/// so that any error can be reported there.
 #[derive(Debug, Default)]
 struct Partials {
-  century: Option<i16>,
-  year_modulo: Option<i16>,
-  hour_12: Option<u8>,
-  pm: Option<u8>, // 0 or 12
+  century: Option<(i32, Span)>,
+  year_modulo: Option<(i32, Span)>,
+  hour_12: Option<(u8, Span)>,
+  pm: Option<(u8, Span)>, // 0 or 12
+  bce: Option<(bool, Span)>,
 }
 
 impl Partials {
   /// Return the full hour.
-  fn hour(&self, src: &str) -> ParseResult<Option<u8>> {
+  fn hour(&self, input: &Input<'_>) -> ParseResult<Option<u8>> {
     match (self.hour_12, self.pm) {
-      (Some(12), Some(pm)) => Ok(Some(12 - pm)),
-      (Some(h), Some(pm)) if h != 12 => Ok(Some(h + pm)),
+      (Some((12, _)), Some((pm, _))) => Ok(Some(pm)),
+      (Some((h, _)), Some((pm, _))) => Ok(Some(h + pm)),
       (None, None) => Ok(None),
-      _ => Err(ParseError::new(src, ErrorKind::Ambiguous))?,
+      (Some((_, span)), None) | (None, Some((_, span))) =>
+        Err(input.err_at(span, ErrorKind::Ambiguous)),
+    }
+  }
+
+  /// Return the astronomical year, given the year within the era (if an era was parsed).
+  fn era_year(&self, year: Option<i32>, input: &Input<'_>) -> ParseResult<Option<i32>> {
+    match (self.bce, year) {
+      (None, _) => Ok(year),
+      (Some((_, span)), None) => Err(input.err_at(span, ErrorKind::Ambiguous)),
+      // Eras count from 1; there is no year 0 AD or 0 BC.
+      (Some((_, span)), Some(y)) if y < 1 => Err(input.err_at(span, ErrorKind::OutOfRange)),
+      (Some((true, _)), Some(y)) => Ok(Some(1 - y)),
+      (Some((false, _)), Some(y)) => Ok(Some(y)),
     }
   }
 
   /// Return the full year.
-  fn year(&self, src: &str, opts: &ParseOptions) -> ParseResult<Option<i16>> {
+  fn year(&self, input: &Input<'_>, opts: &ParseOptions) -> ParseResult<Option<i32>> {
     match (self.century, self.year_modulo) {
-      (Some(c), Some(m)) => Ok(Some(c * 100 + m)),
-      (Some(_), None) => Err(ParseError::new(src, ErrorKind::Ambiguous))?,
-      (None, Some(m)) => Ok(Some((opts.modulo_year_resolution)(m))),
+      (_, Some((m, span))) if !(0..=99).contains(&m) => {
+        let expected = Expected::Range { min: 0, max: 99 };
+        Err(input.err_at(span, ErrorKind::OutOfRange).expecting(expected, None))
+      },
+      (Some((c, span)), Some((m, _))) => match c.checked_mul(100).and_then(|c| c.checked_add(m)) {
+        Some(year) => Ok(Some(year)),
+        None => Err(input.err_at(span, ErrorKind::OutOfRange)),
+      },
+      (Some((_, span)), None) => Err(input.err_at(span, ErrorKind::Ambiguous)),
+      (None, Some((m, _))) => Ok(Some((opts.modulo_year_resolution)(m))),
       (None, None) => Ok(None),
     }
   }
 }
+
+/// Return the directive beginning with the `%` at the given index of the format string: the `%`,
+/// any modifiers, and the specifier.
+fn directive(fmt: &'static str, start: usize) -> &'static str {
+  let rest = &fmt[start + 1..];
+  let modifiers = rest.find(|c| !matches!(c, '-' | '0' | ' ' | '.' | '3' | '6' | '9' | 'E'));
+  let modifiers = modifiers.unwrap_or(rest.len());
+  let specifier = rest[modifiers..].chars().next().map_or(0, char::len_utf8);
+  &fmt[start..start + 1 + modifiers + specifier]
+}
+
+/// Map any failure to convert an integer to `OutOfRange`.
+fn out_of_range<T, E>(result: Result<T, E>) -> Result<T, ErrorKind> {
+  result.map_err(|_| ErrorKind::OutOfRange)
+}
+
+/// Fold the case of a character, for case-insensitive comparison.
+///
+/// Lowercasing, uppercasing, and lowercasing again approximates Unicode full case folding: it maps
+/// (for example) `ß` and `ẞ` to `ss`, and `ς` to `σ`.
+fn fold_case(ch: char) -> impl Iterator<Item = char> {
+  ch.to_lowercase().flat_map(char::to_uppercase).flat_map(char::to_lowercase)
+}
+
+/// Decode the UTF-8 character at the beginning of the given bytes, along with its length in
+/// bytes. Invalid UTF-8 decodes to the replacement character, one byte at a time.
+fn decode_char(bytes: &[u8]) -> Option<(char, usize)> {
+  let first = *bytes.first()?;
+  let len = match first {
+    0x00..=0x7f => return Some((first as char, 1)),
+    0xc0..=0xdf => 2,
+    0xe0..=0xef => 3,
+    0xf0..=0xf7 => 4,
+    _ => return Some((char::REPLACEMENT_CHARACTER, 1)),
+  };
+  match bytes.get(..len).and_then(|b| str::from_utf8(b).ok()).and_then(|s| s.chars().next()) {
+    Some(ch) => Some((ch, len)),
+    None => Some((char::REPLACEMENT_CHARACTER, 1)),
+  }
+}
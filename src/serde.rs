@@ -0,0 +1,171 @@
+//! Helpers for deserializing dates and times from strings with a [`Parser`], enabled by the
+//! `serde` feature.
+//!
+//! The [`with_format!`](crate::serde::with_format) macro generates a module suitable for use with
+//! `#[serde(with = "...")]`, which parses a string field using the given format:
+//!
+//! ```
+//! use serde::Deserialize;
+//! use strptime::RawDate;
+//!
+//! strptime::serde::with_format!(dmy, "%d/%m/%Y");
+//!
+//! #[derive(Deserialize)]
+//! struct Invoice {
+//!   #[serde(with = "dmy")]
+//!   due: RawDate,
+//! }
+//!
+//! let invoice: Invoice = serde_json::from_str(r#"{"due": "21/04/2012"}"#).unwrap();
+//! assert_eq!(invoice.due.month(), 4);
+//! ```
+//!
+//! The field may be any type that can be converted from a [`RawDateTime`], including
+//! [`RawDateTime`] itself, [`RawDate`], [`RawTime`], and any
+//! enabled date library integrations. Since this crate does not format dates, the generated
+//! module only supports deserialization.
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use serde::de::Error;
+use serde::de::Visitor;
+#[doc(hidden)]
+pub use serde::Deserializer;
+
+/// Generate a module that deserializes a string field using the given format.
+///
+/// The first argument is the name of the module to generate (optionally preceded by a
+/// visibility), and the second is the format string. See the [module documentation](self) for
+/// an example.
+#[doc(inline)]
+pub use crate::__serde_with_format as with_format;
+use crate::error::ErrorKind;
+use crate::ParseError;
+use crate::ParseOptions;
+use crate::Parser;
+use crate::RawDate;
+use crate::RawDateTime;
+use crate::RawTime;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serde_with_format {
+  ($vis:vis $name:ident, $fmt:expr) => {
+    $vis mod $name {
+      #[allow(dead_code)]
+      pub fn deserialize<'de, D, T>(deserializer: D) -> ::core::result::Result<T, D::Error>
+      where
+        D: $crate::serde::Deserializer<'de>,
+        T: ::core::convert::TryFrom<$crate::RawDateTime>,
+        T::Error: ::core::fmt::Display,
+      {
+        $crate::serde::deserialize(&$crate::Parser::new($fmt), deserializer)
+      }
+    }
+  };
+}
+
+/// Deserialize a string using the given parser, and convert the result to the requested type.
+#[doc(hidden)]
+pub fn deserialize<'de, D, T>(parser: &Parser, deserializer: D) -> Result<T, D::Error>
+where
+  D: Deserializer<'de>,
+  T: TryFrom<RawDateTime>,
+  T::Error: Display, {
+  deserializer.deserialize_str(ParserVisitor { parser, _phantom: PhantomData })
+}
+
+struct ParserVisitor<'p, T> {
+  parser: &'p Parser,
+  _phantom: PhantomData<T>,
+}
+
+impl<'de, 'p, T> Visitor<'de> for ParserVisitor<'p, T>
+where
+  T: TryFrom<RawDateTime>,
+  T::Error: Display,
+{
+  type Value = T;
+
+  fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "a date or time string matching the format `{}`", self.parser.fmt)
+  }
+
+  fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+    let raw = self.parser.parse(v).map_err(E::custom)?;
+    T::try_from(raw).map_err(E::custom)
+  }
+
+  fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<T, E> {
+    let raw = self.parser.parse_bytes(v).map_err(E::custom)?;
+    T::try_from(raw).map_err(E::custom)
+  }
+}
+
+/// The serialized form of a [`RawDate`], which is validated before conversion.
+#[derive(serde::Deserialize)]
+pub(crate) struct RawDateRepr {
+  year: Option<i32>,
+  quarter: Option<u8>,
+  month: Option<u8>,
+  day: Option<u8>,
+}
+
+impl TryFrom<RawDateRepr> for RawDate {
+  type Error = ParseError;
+
+  fn try_from(repr: RawDateRepr) -> Result<Self, ParseError> {
+    let date =
+      RawDate { year: repr.year, quarter: repr.quarter, month: repr.month, day: repr.day };
+    date.assert_complete(true)?;
+    Ok(date)
+  }
+}
+
+/// The serialized form of a [`RawTime`], which is validated before conversion.
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(crate) struct RawTimeRepr {
+  hour: Option<u8>,
+  minute: Option<u8>,
+  second: Option<u8>,
+  nanosecond: Option<u64>,
+  fraction_digits: Option<u8>,
+  utc_offset: Option<i32>,
+}
+
+impl From<RawTime> for RawTimeRepr {
+  fn from(time: RawTime) -> Self {
+    Self {
+      hour: time.hour,
+      minute: time.minute,
+      second: time.second,
+      nanosecond: time.nanosecond.map(|(nanosecond, _)| nanosecond),
+      fraction_digits: time.nanosecond.map(|(_, digits)| digits),
+      utc_offset: time.utc_offset,
+    }
+  }
+}
+
+impl TryFrom<RawTimeRepr> for RawTime {
+  type Error = ParseError;
+
+  fn try_from(repr: RawTimeRepr) -> Result<Self, ParseError> {
+    let nanosecond = match (repr.nanosecond, repr.fraction_digits) {
+      (Some(nanosecond @ 0..=999_999_999), Some(digits @ 1..=9)) => Some((nanosecond, digits)),
+      (None, None) => None,
+      _ => return Err(ErrorKind::OutOfRange.into()),
+    };
+    let time = RawTime {
+      hour: repr.hour,
+      minute: repr.minute,
+      second: repr.second,
+      nanosecond,
+      utc_offset: repr.utc_offset,
+    };
+    let opts = ParseOptions { leap_seconds: true, end_of_day: true, ..ParseOptions::new() };
+    time.assert_in_range(&opts)?;
+    Ok(time)
+  }
+}
@@ -1,21 +1,29 @@
 #![cfg(test)]
 
+use std::vec::Vec;
+
 use assert2::check;
 
+use crate::error::ErrorKind;
+use crate::DatePrecision;
+use crate::Expected;
+use crate::FractionOverflow;
+use crate::Locale;
 use crate::ParseResult;
 use crate::Parser;
 use crate::RawDate;
 use crate::RawTime;
+use crate::TimePrecision;
 
 impl RawDate {
-  pub(crate) fn ymd(&self) -> (i16, u8, u8) {
+  pub(crate) fn ymd(&self) -> (i32, u8, u8) {
     (self.year.unwrap(), self.month.unwrap(), self.day.unwrap())
   }
 }
 
 impl RawTime {
   pub(crate) fn hms(&self) -> (u8, u8, u8, u64) {
-    (self.hour, self.minute, self.second, self.nanosecond)
+    (self.hour(), self.minute(), self.second(), self.nanosecond())
   }
 }
 
@@ -31,6 +39,64 @@ fn test_parse_ymd() -> ParseResult<()> {
   Ok(())
 }
 
+#[test]
+fn test_signed_years() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d");
+  check!(parser.parse("-0044-03-15")?.date()?.ymd() == (-44, 3, 15));
+  check!(parser.parse("+2012-04-21")?.date()?.ymd() == (2012, 4, 21));
+  check!(parser.parse("0000-03-01")?.date()?.days_since_epoch() == -719_468);
+  check!(parser.parse("-044-03-15").is_err());
+
+  let parser = Parser::new("%Y-%m-%d").year_digits(4, 6);
+  check!(parser.parse("+12345-04-21")?.date()?.ymd() == (12345, 4, 21));
+  check!(parser.parse("-123456-04-21")?.date()?.ymd() == (-123_456, 4, 21));
+  check!(parser.parse("2012-04-21")?.date()?.ymd() == (2012, 4, 21));
+  check!(parser.parse("1234567-04-21").is_err());
+  let parser = Parser::new("%Y-%m-%d").year_digits(5, 5);
+  check!(parser.parse("02012-04-21")?.date()?.ymd() == (2012, 4, 21));
+  check!(parser.parse("2012-04-21").is_err()); // Not taken by the fixed-width fast path.
+
+  let parser = Parser::new("%-Y-%m-%d");
+  check!(parser.parse("2147483647-04-21")?.date()?.year() == i32::MAX);
+  check!(parser.parse("-2147483647-04-21")?.date()?.year() == -i32::MAX);
+  let err = parser.parse("2147483648-04-21").unwrap_err();
+  check!((err.kind, err.index) == (ErrorKind::OutOfRange, Some(0)));
+  let err = parser.parse("99999999999999999999-04-21").unwrap_err();
+  check!(err.kind == ErrorKind::OutOfRange);
+  check!(Parser::new("%Y-%m-%-d").parse("2012-04-256").unwrap_err().kind == ErrorKind::OutOfRange);
+
+  // Centuries and years within the century must not overflow.
+  let err = Parser::new("%-C-%y-%m-%d").parse("99999999-12-04-21").unwrap_err();
+  check!(err.kind == ErrorKind::OutOfRange);
+  check!(Parser::new("%-C-%y-%m-%d").parse("20-12-04-21")?.date()?.year() == 2012);
+  let parser = Parser::new("%-y-%m-%d");
+  check!(parser.parse("2147483647-04-21").unwrap_err().kind == ErrorKind::OutOfRange);
+  check!(parser.parse("100-04-21").unwrap_err().kind == ErrorKind::OutOfRange);
+  check!(parser.parse("12-04-21")?.date()?.year() == 2012);
+  Ok(())
+}
+
+#[test]
+fn test_eras() -> ParseResult<()> {
+  let parser = Parser::new("%-d %B %Ey %EC");
+  check!(parser.parse("15 March 44 BC")?.date()?.ymd() == (-43, 3, 15));
+  check!(parser.parse("25 December 1066 AD")?.date()?.ymd() == (1066, 12, 25));
+  check!(parser.parse("1 January 1 bce")?.date()?.ymd() == (0, 1, 1));
+  check!(parser.parse("1 January 2000 c.e.")?.date()?.ymd() == (2000, 1, 1));
+  check!(parser.parse("1 January 0 BC").unwrap_err().kind == ErrorKind::OutOfRange);
+  check!(parser.parse("1 January 44 BX").is_err());
+
+  let parser = Parser::new("%EC %Y-%m-%d");
+  check!(parser.parse("BCE 0753-04-21")?.date()?.ymd() == (-752, 4, 21));
+  let parser = Parser::new("%-d %B %Y %EC").locale(&Locale::FRENCH);
+  check!(parser.parse("15 mars 0044 av. J.-C.")?.date()?.ymd() == (-43, 3, 15));
+  let parser = Parser::new("%-d. %B %Ey %EC").locale(&Locale::GERMAN);
+  check!(parser.parse("15. März 44 v. Chr.")?.date()?.ymd() == (-43, 3, 15));
+  check!(Parser::new("%m/%d %EC").parse("03/15 BC").unwrap_err().kind == ErrorKind::Ambiguous);
+  check!(Parser::new("%Ed").parse("15").unwrap_err().kind == ErrorKind::InvalidFormat);
+  Ok(())
+}
+
 #[test]
 fn test_parse_weekday() -> ParseResult<()> {
   let parser = Parser::new("%A, %B %-d, %Y");
@@ -58,6 +124,74 @@ fn test_parse_month() -> ParseResult<()> {
   Ok(())
 }
 
+#[test]
+fn test_locales() -> ParseResult<()> {
+  let parser = Parser::new("%A %-d %B %Y").locale(&Locale::FRENCH);
+  check!(parser.parse("samedi 21 avril 2012")?.date()?.ymd() == (2012, 4, 21));
+  check!(parser.parse("Mercredi 1 févr. 2012")?.date()?.ymd() == (2012, 2, 1));
+  check!(parser.parse("jeudi 5 juil 2012")?.date()?.ymd() == (2012, 7, 5));
+  let parser = Parser::new("%a, %-d. %B %Y").locale(&Locale::GERMAN);
+  check!(parser.parse("Sa., 21. April 2012")?.date()?.ymd() == (2012, 4, 21));
+  check!(parser.parse("Mo., 5. März 2012")?.date()?.ymd() == (2012, 3, 5));
+  check!(parser.parse("Di., 4. Sept. 2012")?.date()?.ymd() == (2012, 9, 4));
+  let parser = Parser::new("%-d de %B de %Y, %I:%M %p").locale(&Locale::SPANISH);
+  let dt = parser.parse("21 de abril de 2012, 11:00 p. m.")?;
+  check!(dt.date()?.ymd() == (2012, 4, 21));
+  check!(dt.time()?.hms() == (23, 0, 0, 0));
+  check!(Parser::new("%B").locale(&Locale::FRENCH).parse("April").is_err());
+  let parser = Parser::new("%B %Y").allow_partial_dates();
+  check!(parser.parse("Sept 2012")?.date()?.month_opt() == Some(9));
+  Ok(())
+}
+
+#[test]
+fn test_unicode_names() -> ParseResult<()> {
+  let parser = Parser::new("%a %-d %B %Y").locale(&Locale::FRENCH);
+  for d in ["mer. 1 févr. 2012", "MER. 1 FÉVR. 2012", "Mer. 1 Février 2012", "mer. 1 FÉVRI 2012"]
+  {
+    check!(parser.parse(d)?.date()?.ymd() == (2012, 2, 1));
+  }
+  check!(parser.parse("mer. 1 fevrier 2012").is_err());
+  let parser = Parser::new("%A, %-d. %B %Y").locale(&Locale::GERMAN);
+  for d in ["Montag, 5. März 2012", "MONTAG, 5. MÄRZ 2012", "montag, 5. märz 2012"] {
+    check!(parser.parse(d)?.date()?.ymd() == (2012, 3, 5));
+  }
+  let parser = Parser::new("%A %B %-d %Y").locale(&Locale::SPANISH);
+  check!(parser.parse("MIÉRCOLES MARZO 7 2012")?.date()?.ymd() == (2012, 3, 7));
+  check!(parser.parse("sÁbado Abril 21 2012")?.date()?.ymd() == (2012, 4, 21));
+
+  // Characters whose case folding expands to several characters.
+  static NAMES: &[(&str, u8)] = &[("Été", 7), ("Größe", 8), ("Σεπτέμβριος", 9)];
+  let parser = Parser::new("%B %-d %Y").month_names(NAMES);
+  check!(parser.parse("ÉTÉ 1 2012")?.date()?.ymd() == (2012, 7, 1));
+  check!(parser.parse("GRÖSSE 1 2012")?.date()?.ymd() == (2012, 8, 1));
+  check!(parser.parse("größe 1 2012")?.date()?.ymd() == (2012, 8, 1));
+  check!(parser.parse("ΣΕΠΤΈΜΒΡΙΟΣ 1 2012")?.date()?.ymd() == (2012, 9, 1));
+  check!(parser.parse("σεπτέμβριος 1 2012")?.date()?.ymd() == (2012, 9, 1));
+  check!(parser.parse("GRÖS 1 2012").is_err());
+  Ok(())
+}
+
+#[test]
+fn test_custom_names() -> ParseResult<()> {
+  static MONTHS: &[(&str, u8)] = &[("Sept", 9), ("Month 1", 1), ("Month 11", 11), ("Apr", 5)];
+  let parser = Parser::new("%a %B %-d %Y").month_names(MONTHS).weekday_names(&[("Thur", 4)]);
+  check!(parser.parse("Thur Sept 20 2012")?.date()?.ymd() == (2012, 9, 20));
+  check!(parser.parse("thur month 11 22 2012")?.date()?.ymd() == (2012, 11, 22));
+  check!(parser.parse("Thu Month 1 5 2012")?.date()?.ymd() == (2012, 1, 5));
+  check!(parser.parse("Thu September 20 2012")?.date()?.ymd() == (2012, 9, 20));
+  check!(parser.parse("Sat Apr 21 2012")?.date()?.ymd() == (2012, 5, 21)); // Custom wins ties
+  check!(parser.parse("Sat April 21 2012")?.date()?.ymd() == (2012, 4, 21)); // Longest match
+  check!(parser.parse("Thurs Sept 20 2012").is_err());
+  Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Month numbers must be between 1 and 12")]
+fn test_custom_names_out_of_range() {
+  let _ = Parser::new("%b %Y-%m").month_names(&[("Foo", 13)]);
+}
+
 #[test]
 fn test_parse_single_digits() -> ParseResult<()> {
   let parser = Parser::new("%-m/%-d/%Y");
@@ -66,6 +200,28 @@ fn test_parse_single_digits() -> ParseResult<()> {
   Ok(())
 }
 
+#[test]
+fn test_unicode_digits() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%z").allow_unicode_digits();
+  for d in [
+    "٢٠١٢-٠٤-٢١ ١١:٠٠:٠٠+٠٣٠٠",                   // Arabic-Indic
+    "۲۰۱۲-۰۴-۲۱ ۱۱:۰۰:۰۰+۰۳۰۰",                   // Persian
+    "२०१२-०४-२१ ११:००:००+०३००",                   // Devanagari
+    "２０１２-０４-２１ １１:００:００+０３００", // Full-width
+    "2012-٠4-21 ११:00:00+0300",                   // Mixed
+  ] {
+    let dt = parser.parse(d)?;
+    check!(dt.date()?.ymd() == (2012, 4, 21));
+    check!(dt.time()?.hms() == (11, 0, 0, 0));
+    check!(dt.time()?.utc_offset() == Some(10800));
+  }
+  let parser = Parser::new("%e/%-m/%Y").allow_unicode_digits();
+  check!(parser.parse(" ٢/٤/٢٠١٢")?.date()?.ymd() == (2012, 4, 2));
+  check!(Parser::new("%e/%-m/%Y").parse(" ٢/٤/٢٠١٢").is_err());
+  check!(Parser::new("%-m/%-d/%Y").allow_unicode_digits().parse("4/²/2012").is_err());
+  Ok(())
+}
+
 #[test]
 fn test_parse_time() -> ParseResult<()> {
   let parser = Parser::new("%Y-%m-%d %H:%M:%S");
@@ -89,6 +245,38 @@ fn test_12h_time() -> ParseResult<()> {
   check!(Parser::new("%I:%M %P").parse("11:30 am")?.time()?.hms() == (11, 30, 0, 0));
   check!(Parser::new("%I:%M %P").parse("11:30 pm")?.time()?.hms() == (23, 30, 0, 0));
   check!(Parser::new("%I:%M %p").parse("11:30 PM")?.time()?.hms() == (23, 30, 0, 0));
+  check!(Parser::new("%I:%M %p").parse("12:30 AM")?.time()?.hms() == (0, 30, 0, 0));
+  check!(Parser::new("%I:%M %p").parse("12:30 PM")?.time()?.hms() == (12, 30, 0, 0));
+  check!(Parser::new("%I:%M %p").parse("00:30 PM").unwrap_err().kind == ErrorKind::OutOfRange);
+  Ok(())
+}
+
+#[test]
+fn test_time_ranges() -> ParseResult<()> {
+  let parser = Parser::new("%H:%M:%S");
+  for t in ["24:00:00", "23:60:00", "23:59:60", "99:99:99"] {
+    check!(parser.parse(t).is_err());
+  }
+  check!(Parser::new("%I:%M %p").parse("13:00 PM").is_err());
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%z").allow_leap_seconds();
+  let dt = parser.parse("2016-12-31 23:59:60+0000")?;
+  check!(dt.time()?.is_leap_second());
+  check!(!dt.time()?.is_end_of_day());
+  check!(dt.time()?.hms() == (23, 59, 60, 0));
+  check!(dt.unix_timestamp()? == 1_483_228_800);
+  check!(parser.parse("2012-04-21 24:00:00+0000").is_err());
+
+  let parser = Parser::new("%H:%M:%S%.-f").allow_end_of_day();
+  let time = parser.parse("24:00:00.0")?.time()?;
+  check!(time.is_end_of_day());
+  check!(time.hms() == (24, 0, 0, 0));
+  check!(Parser::new("%H").allow_end_of_day().parse("24")?.time()?.is_end_of_day());
+  for t in ["24:00:01.0", "24:01:00.0", "24:00:00.1", "23:59:60.0"] {
+    check!(parser.parse(t).is_err());
+  }
+  let dt = Parser::new("%Y-%m-%d %H:%M").allow_end_of_day().parse("2012-04-21 24:00")?;
+  check!(dt.unix_timestamp_with_default_offset(0)? == 1_335_052_800);
   Ok(())
 }
 
@@ -110,6 +298,37 @@ fn test_nanos() -> ParseResult<()> {
   Ok(())
 }
 
+#[test]
+fn test_variable_fraction() -> ParseResult<()> {
+  let parser = Parser::new("%H:%M:%S%.-f");
+  for (t, nanos, digits) in [
+    ("11:00:00.5", 500_000_000, 1),
+    ("11:00:00.1234", 123_400_000, 4),
+    ("11:00:00.000001", 1000, 6),
+    ("11:00:00.123456789", 123_456_789, 9),
+  ] {
+    let time = parser.parse(t)?.time()?;
+    check!(time.nanosecond() == nanos);
+    check!(time.precision() == Some(TimePrecision::Fraction(digits)));
+  }
+  check!(parser.parse("11:00:00.").is_err());
+  check!(parser.parse("11:00:00.1234567891").is_err());
+
+  let parser = Parser::new("%H:%M:%S,%-f").fraction_overflow(FractionOverflow::Truncate);
+  check!(parser.parse("11:00:00,1234567899")?.time()?.nanosecond() == 123_456_789);
+  let parser = Parser::new("%H:%M:%S,%-f").fraction_overflow(FractionOverflow::Round);
+  check!(parser.parse("11:00:00,1234567894")?.time()?.nanosecond() == 123_456_789);
+  check!(parser.parse("11:00:00,12345678950")?.time()?.nanosecond() == 123_456_790);
+  check!(parser.parse("11:00:00,99999999999")?.time()?.hms() == (11, 0, 0, 999_999_999));
+
+  // Padding carries over to later specifiers, but does not make `%f` variable-length.
+  check!(Parser::new("%-m/%d/%Y").parse("4/2/2012")?.date()?.ymd() == (2012, 4, 2));
+  let parser = Parser::new("%-H:%M:%S.%3f");
+  check!(parser.parse("9:00:00.250")?.time()?.nanosecond() == 250_000_000);
+  check!(parser.parse("9:00:00.2500").is_err());
+  Ok(())
+}
+
 #[test]
 fn test_nanos_trailing() -> ParseResult<()> {
   let dt = Parser::new("%H:%M:%S%.6f %Y-%m-%d").parse("11:00:00.000000 2012-04-21")?;
@@ -127,6 +346,13 @@ fn test_utc_offset() -> ParseResult<()> {
   );
   check!(Parser::new("%H:%M:%S%z").parse("11:00:00+0200")?.time()?.utc_offset().unwrap() == 7200);
   check!(Parser::new("%H:%M:%S%z").parse("11:00:00+0000")?.time()?.utc_offset().unwrap() == 0);
+  for input in ["11:00:00+0060", "11:00:00-2400"] {
+    let err = Parser::new("%H:%M:%S%z").parse(input).unwrap_err();
+    check!(err.kind == ErrorKind::OutOfRange);
+    check!(err.index == Some(8));
+    check!(err.format_index == Some(8));
+    check!(err.expected == Some(Expected::UtcOffset));
+  }
   Ok(())
 }
 
@@ -152,3 +378,544 @@ fn test_errors() -> ParseResult<()> {
   check!(Parser::new("%Y-%m-%dT%H:%M:%S%.6f%z").parse("2024-07-04T15:30:45.123456789").is_err());
   Ok(())
 }
+
+#[test]
+fn test_error_details() {
+  let details = |fmt, input| {
+    let err = Parser::new(fmt).parse(input).unwrap_err();
+    (err.kind, err.index, err.specifier, err.format_index, err.expected, err.found)
+  };
+  let digits = |min, max| Some(Expected::Digits { min, max });
+  check!(
+    details("%Y-%m-%d", "12-14-21")
+      == (ErrorKind::Unexpected, Some(0), Some("%Y"), Some(0), digits(4, 4), Some('-'))
+  );
+  check!(
+    details("%m/%d/%Y", "7/4/1776")
+      == (ErrorKind::Unexpected, Some(0), Some("%m"), Some(0), digits(2, 2), Some('/'))
+  );
+  check!(
+    details("%Y-%m-%d", "2012-04-")
+      == (ErrorKind::Unexpected, Some(8), Some("%d"), Some(6), digits(2, 2), None)
+  );
+  check!(
+    details("%Y-%m-%-d", "2012-04-x")
+      == (ErrorKind::Unexpected, Some(8), Some("%-d"), Some(6), digits(1, usize::MAX), Some('x'))
+  );
+  check!(
+    details("%Y/%m", "2012-04")
+      == (ErrorKind::Unexpected, Some(4), None, Some(2), Some(Expected::Char('/')), Some('-'))
+  );
+  check!(
+    details("%Y-%m-%d", "2012-04")
+      == (ErrorKind::InputTooShort, Some(7), None, Some(5), Some(Expected::Char('-')), None)
+  );
+  check!(
+    details("%H:%M%z", "11:30Z")
+      == (ErrorKind::Unexpected, Some(5), Some("%z"), Some(5), Some(Expected::Sign), Some('Z'))
+  );
+  check!(
+    details("%d %b", "21 Avr")
+      == (
+        ErrorKind::Unexpected,
+        Some(3),
+        Some("%b"),
+        Some(3),
+        Some(Expected::MonthName),
+        Some('A')
+      )
+  );
+  check!(
+    details("%I:%M %p", "11:30 P")
+      == (ErrorKind::Unexpected, Some(6), Some("%p"), Some(6), Some(Expected::AmPm), Some('P'))
+  );
+  check!(
+    details("%H:%M:%S%.-f", "11:30:00.1234567890")
+      == (ErrorKind::Unexpected, Some(18), Some("%.-f"), Some(8), digits(1, 9), Some('0'))
+  );
+  check!(
+    details("%Y-%m-%d", "2012-04-21T11:00")
+      == (ErrorKind::InputTooLong, Some(10), None, None, Some(Expected::End), Some('T'))
+  );
+  check!(
+    details("%-Y-%m-%d", "2147483648-04-21")
+      == (ErrorKind::OutOfRange, Some(0), Some("%-Y"), Some(0), None, None)
+  );
+  check!(
+    details("%Y-%Q", "2012-Q1")
+      == (ErrorKind::InvalidFormat, Some(5), Some("%Q"), Some(3), None, None)
+  );
+
+  // Values out of range are reported where they were parsed.
+  let range = |min, max| Some(Expected::Range { min, max });
+  check!(
+    details("%H:%M", "11:61")
+      == (ErrorKind::OutOfRange, Some(3), Some("%M"), Some(3), range(0, 59), None)
+  );
+  check!(
+    details("%Y-%m-%d", "2012-13-01")
+      == (ErrorKind::OutOfRange, Some(5), Some("%m"), Some(3), range(1, 12), None)
+  );
+  check!(
+    details("Q%q %Y-%m-%d", "Q3 2012-04-21")
+      == (ErrorKind::OutOfRange, Some(8), Some("%m"), Some(7), range(7, 9), None)
+  );
+  check!(
+    details("%Y-%m-%d Q%q", "2012-04-21 Q3")
+      == (ErrorKind::OutOfRange, Some(12), Some("%q"), Some(10), range(2, 2), None)
+  );
+  let err = Parser::new("%H:%M:%S").allow_leap_seconds().parse("23:59:61").unwrap_err();
+  check!((err.specifier, err.expected) == (Some("%S"), range(0, 60)));
+  let err = Parser::new("%H:%M").allow_end_of_day().parse("24:30").unwrap_err();
+  check!((err.index, err.specifier, err.expected) == (Some(0), Some("%H"), range(0, 23)));
+
+  // Fields that are combined after parsing report errors where they were parsed.
+  check!(
+    details("%I:%M", "11:30") == (ErrorKind::Ambiguous, Some(0), Some("%I"), Some(0), None, None)
+  );
+  check!(
+    details("%H:%M %p", "11:30 PM")
+      == (ErrorKind::Ambiguous, Some(6), Some("%p"), Some(6), None, None)
+  );
+  check!(
+    details("%C-%m-%d", "20-04-21")
+      == (ErrorKind::Ambiguous, Some(0), Some("%C"), Some(0), None, None)
+  );
+  check!(
+    details("%-d %B %Ey %EC", "1 January 0 BC")
+      == (ErrorKind::OutOfRange, Some(12), Some("%EC"), Some(11), None, None)
+  );
+  check!(
+    details("%-y-%m-%d", "100-04-21")
+      == (ErrorKind::OutOfRange, Some(0), Some("%-y"), Some(0), range(0, 99), None)
+  );
+
+  #[cfg(feature = "alloc")]
+  check!(
+    std::string::ToString::to_string(&Parser::new("%H:%M").parse("11:61").unwrap_err())
+      == "11:61\n   ^-----\nValue is out of range: expected a value from 0 to 59 (`%M` at format \
+          index 3)"
+  );
+  #[cfg(feature = "alloc")]
+  check!(
+    std::string::ToString::to_string(&Parser::new("%Y-%m-%d").parse("2012-4-21").unwrap_err())
+      == "2012-4-21\n     ^-----\nInput does not conform to format string: expected 2 digits, \
+          found `-` (`%m` at format index 3)"
+  );
+}
+
+#[test]
+fn test_partial_dates() -> ParseResult<()> {
+  let date = Parser::new("%B %Y").allow_partial_dates().parse("April 2012")?.date()?;
+  check!(date.precision() == DatePrecision::YearMonth);
+  check!((date.year_opt(), date.month_opt(), date.day_opt()) == (Some(2012), Some(4), None));
+  let date = Parser::new("%Y").allow_partial_dates().parse("2012")?.date()?;
+  check!(date.precision() == DatePrecision::Year);
+  let date = Parser::new("%m/%d").allow_partial_dates().parse("04/21")?.date()?;
+  check!(date.precision() == DatePrecision::MonthDay);
+  check!(date.year_opt().is_none());
+  let date = Parser::new("%Y-%m-%d").allow_partial_dates().parse("2012-04-21")?.date()?;
+  check!(date.precision() == DatePrecision::YearMonthDay);
+  Ok(())
+}
+
+#[test]
+fn test_partial_dates_errors() {
+  check!(Parser::new("%B %Y").parse("April 2012").is_err()); // Partial dates not allowed
+  check!(Parser::new("%Y/%d").allow_partial_dates().parse("2012/21").is_err()); // No month
+  check!(Parser::new("%d").allow_partial_dates().parse("21").is_err()); // Day alone
+}
+
+#[test]
+fn test_quarters() -> ParseResult<()> {
+  let parser = Parser::new("%Y-Q%q").allow_partial_dates();
+  let date = parser.parse("2012-Q2")?.date()?;
+  check!(date.precision() == DatePrecision::YearQuarter);
+  check!((date.year(), date.quarter(), date.month_opt(), date.day_opt()) == (2012, 2, None, None));
+  check!(parser.parse("2012-Q5").unwrap_err().kind == ErrorKind::OutOfRange);
+  check!(parser.parse("2012-Q0").unwrap_err().kind == ErrorKind::OutOfRange);
+  check!(Parser::new("%Y-Q%q").parse("2012-Q2").unwrap_err().kind == ErrorKind::IncompleteDate);
+
+  let parser = Parser::new("%Y-Q%q").default_day(1);
+  let date = parser.parse("2012-Q4")?.date()?;
+  check!(date.precision() == DatePrecision::YearMonthDay);
+  check!((date.ymd(), date.quarter()) == ((2012, 10, 1), 4));
+  check!(Parser::new("%Y-%m").default_day(15).parse("2012-04")?.date()?.ymd() == (2012, 4, 15));
+  check!(Parser::new("%Y%m").default_day(1).parse("201204")?.date()?.ymd() == (2012, 4, 1));
+  let parser = Parser::new("%Y-%m").default_day(31);
+  check!(parser.parse("2012-02")?.date()?.ymd() == (2012, 2, 29));
+  check!(parser.parse("2011-02")?.date()?.ymd() == (2011, 2, 28));
+  check!(parser.parse("2012-04")?.unix_timestamp_with_default_offset(0)? == 1_335_744_000);
+
+  // The quarter must agree with the month.
+  let parser = Parser::new("Q%q %Y-%m-%d");
+  check!(parser.parse("Q2 2012-04-21")?.date()?.quarter() == 2);
+  check!(parser.parse("Q3 2012-04-21").unwrap_err().kind == ErrorKind::OutOfRange);
+  let parser = Parser::new("Q%q %b %Y").allow_partial_dates();
+  check!(parser.parse("Q2 Apr 2012")?.date()?.month_opt() == Some(4));
+  let err = parser.parse("Q3 Apr 2012").unwrap_err();
+  check!((err.kind, err.index, err.format_index) == (ErrorKind::OutOfRange, Some(3), Some(4)));
+  check!(err.expected == Some(Expected::Range { min: 7, max: 9 }));
+  check!(Parser::new("%Y-%m-%d").parse("2012-04-21")?.date()?.quarter_opt() == Some(2));
+
+  // Months and days outside the calendar are out of range.
+  let parser = Parser::new("Q%q %Y-%-m-%d");
+  check!(parser.parse("Q1 2012-255-01").unwrap_err().kind == ErrorKind::OutOfRange);
+  let parser = Parser::new("%Y-%-m-%d");
+  for date in ["2012-255-01", "2012-13-01", "2012-0-01", "2012-04-32", "2012-04-00"] {
+    check!(parser.parse(date).unwrap_err().kind == ErrorKind::OutOfRange, "{date}");
+  }
+  Ok(())
+}
+
+#[test]
+fn test_time_precision() -> ParseResult<()> {
+  let time = Parser::new("%H:%M").parse("11:00")?.time()?;
+  check!(time.precision() == Some(TimePrecision::Minute));
+  check!((time.hour_opt(), time.minute_opt(), time.second_opt()) == (Some(11), Some(0), None));
+  let time = Parser::new("%H:%M:%S").parse("11:00:00")?.time()?;
+  check!(time.precision() == Some(TimePrecision::Second));
+  check!(time.second_opt() == Some(0));
+  check!(time.nanosecond_opt().is_none());
+  let time = Parser::new("%H:%M:%S%.3f").parse("11:00:00.000")?.time()?;
+  check!(time.precision() == Some(TimePrecision::Fraction(3)));
+  check!(Parser::new("%I %p").parse("11 AM")?.time()?.precision() == Some(TimePrecision::Hour));
+  check!(Parser::new("%z").parse("-0400")?.time()?.precision().is_none());
+  Ok(())
+}
+
+#[test]
+fn test_days_since_epoch() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d");
+  check!(parser.parse("1970-01-01")?.date()?.days_since_epoch() == 0);
+  check!(parser.parse("1969-12-31")?.date()?.days_since_epoch() == -1);
+  check!(parser.parse("2000-03-01")?.date()?.days_since_epoch() == 11_017);
+  check!(parser.parse("1776-07-04")?.date()?.days_since_epoch() == -70_672);
+  Ok(())
+}
+
+#[test]
+fn test_unix_timestamp() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%.3f%z");
+  let dt = parser.parse("2012-04-21 11:00:00.250-0400")?;
+  check!(dt.unix_timestamp()? == 1_335_020_400);
+  check!(dt.unix_timestamp_nanos()? == 1_335_020_400_250_000_000);
+  let dt = Parser::new("%Y-%m-%d %H:%M:%S").parse("1969-12-31 23:59:59")?;
+  check!(dt.unix_timestamp().is_err());
+  check!(dt.unix_timestamp_with_default_offset(0)? == -1);
+  check!(dt.unix_timestamp_nanos_with_default_offset(3600)? == -3_601_000_000_000);
+  let dt = Parser::new("%Y-%m-%d").parse("2012-04-21")?;
+  check!(dt.unix_timestamp_with_default_offset(0)? == 1_334_966_400);
+  check!(Parser::new("%H:%M%z").parse("11:00+0000")?.unix_timestamp().is_err());
+
+  // Dates that do not exist are out of range.
+  let timestamp =
+    |s| Parser::new("%Y-%m-%d").parse(s).and_then(|dt| dt.unix_timestamp_with_default_offset(0));
+  check!(timestamp("2012-02-29")? == 1_330_473_600);
+  check!(timestamp("2000-02-29").is_ok());
+  for date in ["2011-02-29", "1900-02-29", "2012-04-31", "2012-02-31", "2012-13-01", "2012-04-00"]
+  {
+    check!(matches!(timestamp(date).unwrap_err().kind, ErrorKind::OutOfRange), "{date}");
+  }
+  Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_system_time() -> ParseResult<()> {
+  use std::time::Duration;
+  use std::time::SystemTime;
+  use std::time::UNIX_EPOCH;
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%.3f%z");
+  let st = SystemTime::try_from(parser.parse("2012-04-21 11:00:00.250-0400")?)?;
+  check!(st == UNIX_EPOCH + Duration::new(1_335_020_400, 250_000_000));
+  let st = SystemTime::try_from(&parser.parse("1969-12-31 23:59:59.500+0000")?)?;
+  check!(st == UNIX_EPOCH - Duration::from_millis(500));
+  let dt = Parser::new("%Y-%m-%d").parse("1970-01-02")?;
+  check!(SystemTime::try_from(&dt).is_err()); // No UTC offset
+  check!(dt.system_time_with_default_offset(0)? == UNIX_EPOCH + Duration::from_secs(86_400));
+  Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() -> ParseResult<()> {
+  use chrono::DateTime;
+  use chrono::FixedOffset;
+  use chrono::NaiveDate;
+  use chrono::NaiveDateTime;
+  use chrono::NaiveTime;
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%.3f%z");
+  let dt = parser.parse("2012-04-21 11:00:00.250-0400")?;
+  let ymd = NaiveDate::from_ymd_opt(2012, 4, 21).unwrap();
+  check!(NaiveDate::try_from(dt.date()?)? == ymd);
+  check!(
+    NaiveTime::try_from(dt.time()?)? == NaiveTime::from_hms_milli_opt(11, 0, 0, 250).unwrap()
+  );
+  let naive = NaiveDateTime::try_from(dt)?;
+  check!(naive == ymd.and_hms_milli_opt(11, 0, 0, 250).unwrap());
+  let aware = DateTime::<FixedOffset>::try_from(dt)?;
+  check!(aware.offset().local_minus_utc() == -14400);
+  check!(aware.naive_local() == naive);
+  check!(Parser::new("%Y-%m-%d").parse_chrono::<NaiveDateTime>("2012-04-21")? == ymd.into());
+  check!(Parser::new("%Y-%m-%d").parse_chrono::<DateTime<FixedOffset>>("2012-04-21").is_err());
+  check!(Parser::new("%Y-%m-%d").parse_chrono::<NaiveDate>("2012-02-30").is_err());
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S").allow_leap_seconds().allow_end_of_day();
+  let leap = parser.parse("2016-12-31 23:59:60")?;
+  check!(
+    NaiveTime::try_from(leap.time()?)? == NaiveTime::from_hms_milli_opt(23, 59, 59, 1000).unwrap()
+  );
+  let end = NaiveDateTime::try_from(parser.parse("2012-04-21 24:00:00")?)?;
+  check!(end == NaiveDate::from_ymd_opt(2012, 4, 22).unwrap().into());
+  check!(NaiveTime::try_from(parser.parse("2012-04-21 24:00:00")?.time()?).is_err());
+  Ok(())
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_crate() -> ParseResult<()> {
+  use time::Date;
+  use time::Month;
+  use time::OffsetDateTime;
+  use time::PrimitiveDateTime;
+  use time::Time;
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%.9f%z");
+  let dt = parser.parse("2012-04-21 11:00:00.000000250-0400")?;
+  let ymd = Date::from_calendar_date(2012, Month::April, 21).unwrap();
+  let hms = Time::from_hms_nano(11, 0, 0, 250).unwrap();
+  check!(Date::try_from(dt.date()?)? == ymd);
+  check!(Time::try_from(dt.time()?)? == hms);
+  check!(PrimitiveDateTime::try_from(dt)? == PrimitiveDateTime::new(ymd, hms));
+  let odt = OffsetDateTime::try_from(dt)?;
+  check!(odt.offset().whole_seconds() == -14400);
+  check!(odt.unix_timestamp() == 1_335_020_400);
+  check!(OffsetDateTime::try_from(Parser::new("%Y-%m-%d").parse("2012-04-21")?).is_err());
+  check!(Date::try_from(Parser::new("%Y-%m-%d").parse("2012-02-30")?).is_err());
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S").allow_leap_seconds().allow_end_of_day();
+  check!(PrimitiveDateTime::try_from(parser.parse("2016-12-31 23:59:60")?).is_err());
+  let end = PrimitiveDateTime::try_from(parser.parse("2012-04-21 24:00:00")?)?;
+  check!(end == Date::from_calendar_date(2012, Month::April, 22).unwrap().midnight());
+  Ok(())
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff() -> ParseResult<()> {
+  use jiff::civil::Date;
+  use jiff::civil::DateTime;
+  use jiff::civil::Time;
+  use jiff::Timestamp;
+  use jiff::Zoned;
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S%.3f%z");
+  let dt = parser.parse("2012-04-21 11:00:00.250-0400")?;
+  check!(Date::try_from(dt.date()?)? == jiff::civil::date(2012, 4, 21));
+  check!(Time::try_from(dt.time()?)? == jiff::civil::time(11, 0, 0, 250_000_000));
+  let civil = DateTime::try_from(dt)?;
+  check!(civil == jiff::civil::datetime(2012, 4, 21, 11, 0, 0, 250_000_000));
+  let ts = Timestamp::try_from(dt)?;
+  check!(ts.as_millisecond() == 1_335_020_400_250);
+  let zoned = Zoned::try_from(dt)?;
+  check!(zoned.offset().seconds() == -14400);
+  check!(zoned.datetime() == civil);
+  check!(Timestamp::try_from(Parser::new("%Y-%m-%d").parse("2012-04-21")?).is_err());
+
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S").allow_leap_seconds().allow_end_of_day();
+  check!(DateTime::try_from(parser.parse("2016-12-31 23:59:60")?).is_err());
+  let end = DateTime::try_from(parser.parse("2012-04-21 24:00:00")?)?;
+  check!(end == jiff::civil::date(2012, 4, 22).into());
+  Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() -> Result<(), serde_json::Error> {
+  use serde::Deserialize;
+
+  use crate::RawDateTime;
+
+  crate::serde::with_format!(dmy, "%d/%m/%Y");
+  crate::serde::with_format!(hm, "%H:%M");
+
+  #[derive(Deserialize)]
+  struct Row {
+    #[serde(with = "dmy")]
+    date: RawDate,
+    #[serde(with = "hm")]
+    time: RawTime,
+    #[serde(with = "dmy")]
+    raw: RawDateTime,
+  }
+
+  let row: Row =
+    serde_json::from_str(r#"{"date": "21/04/2012", "time": "11:30", "raw": "04/07/1776"}"#)?;
+  check!(row.date.ymd() == (2012, 4, 21));
+  check!(row.time.hms() == (11, 30, 0, 0));
+  check!(row.raw.date().unwrap().ymd() == (1776, 7, 4));
+  check!(serde_json::from_str::<Row>(r#"{"date": "2012-04-21", "time": "11:30"}"#).is_err());
+
+  let json = serde_json::to_string(&row.raw)?;
+  let raw: RawDateTime = serde_json::from_str(&json)?;
+  check!(raw.date().unwrap().ymd() == (1776, 7, 4));
+
+  // Times serialize their fractional second as separate fields.
+  let time = Parser::new("%H:%M:%S%.3f").parse("11:30:00.250").unwrap().time().unwrap();
+  let json = serde_json::to_string(&time)?;
+  check!(json.contains(r#""nanosecond":250000000,"fraction_digits":3"#));
+  let time: RawTime = serde_json::from_str(&json)?;
+  check!(time.precision() == Some(TimePrecision::Fraction(3)));
+
+  // Deserialization rejects values the parser could not have produced.
+  let date = |json| serde_json::from_str::<RawDate>(json);
+  check!(date(r#"{"year": 2012, "month": 4, "day": null}"#)?.month() == 4);
+  check!(date(r#"{"year": null, "quarter": null, "month": null, "day": null}"#).is_err());
+  check!(date(r#"{"year": 2012, "quarter": 3, "month": 4, "day": 21}"#).is_err());
+  let time = |json| serde_json::from_str::<RawTime>(json);
+  check!(time(r#"{"hour": 23, "minute": 59, "second": 60}"#)?.is_leap_second());
+  check!(time(r#"{"hour": 99, "minute": 0}"#).is_err());
+  check!(time(r#"{"hour": 11, "nanosecond": 5}"#).is_err());
+  check!(time(r#"{"hour": 11, "nanosecond": 1000000000, "fraction_digits": 9}"#).is_err());
+  Ok(())
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn test_alloc_free_errors() {
+  use std::string::ToString;
+
+  let err = Parser::new("%Y-%m-%d").parse("2012/04/21").unwrap_err();
+  check!(err.index == Some(4));
+  check!(
+    err.to_string()
+      == "Input does not conform to format string (at index 4): expected `-`, found `/` (format \
+          index 2)"
+  );
+  let err = Parser::new("%Y-%m-%d").parse("2012-4-21").unwrap_err();
+  check!(
+    err.to_string()
+      == "Input does not conform to format string (at index 5): expected 2 digits, found `-` \
+          (`%m` at format index 3)"
+  );
+}
+
+#[test]
+fn test_parse_bytes() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S");
+  let dt = parser.parse_bytes(b"2012-04-21 11:00:00")?;
+  check!(dt.date()?.ymd() == (2012, 4, 21));
+  check!(dt.time()?.hms() == (11, 0, 0, 0));
+  check!(Parser::new("%-d %B %Y").parse_bytes(b"21 april 2012")?.date()?.ymd() == (2012, 4, 21));
+  check!(Parser::new("%H\u{b7}%M").parse_bytes("11\u{b7}30".as_bytes())?.time()?.minute() == 30);
+  let err = parser.parse_bytes(b"2012-04-21 \xff1:00:00").unwrap_err();
+  check!(err.index == Some(11));
+  let err = parser.parse_bytes(b"2012-04-21 11:00:00\xfe").unwrap_err();
+  check!(err.index == Some(19));
+  Ok(())
+}
+
+#[test]
+fn test_parse_prefix() -> ParseResult<()> {
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S");
+  let (dt, rest) = parser.parse_prefix("2012-04-21 11:00:00 ERROR something")?;
+  check!(dt.date()?.ymd() == (2012, 4, 21));
+  check!(dt.time()?.hms() == (11, 0, 0, 0));
+  check!(rest == " ERROR something");
+  check!(parser.parse_prefix("2012-04-21 11:00:00")?.1 == "");
+  check!(parser.parse_prefix("2012-04-21 11:00").is_err());
+  let (_, rest) = Parser::new("%B %-d").allow_partial_dates().parse_prefix("Sept 2 élan")?;
+  check!(rest == " élan");
+  let (_, rest) = parser.parse_bytes_prefix(b"2012-04-21 11:00:00\xff\xfe")?;
+  check!(rest == b"\xff\xfe");
+  Ok(())
+}
+
+#[test]
+fn test_find_iter() -> ParseResult<()> {
+  let text = "[2012-04-21 11:00:00] start\n[2012-04-21 11:00:05] 2012-04-2 done";
+  let parser = Parser::new("%Y-%m-%d %H:%M:%S");
+  let found = parser.find_iter(text).map(|(range, dt)| (range, dt.time().unwrap().second()));
+  check!(found.collect::<Vec<_>>() == [(1..20, 0), (29..48, 5)]);
+  let parser = Parser::new("[%Y-%m-%d %H:%M:%S]");
+  check!(parser.find_iter(text).count() == 2);
+  let parser = Parser::new("%B %-d");
+  let found: Vec<_> = parser.allow_partial_dates().find_iter("Due Apr 21 or May 1.").collect();
+  check!(found.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>() == [4..10, 14..19]);
+  check!(Parser::new("%Y-%m-%d").find_iter("no dates here").next().is_none());
+
+  // Signed years include their sign.
+  let year = |parser: Parser| {
+    parser
+      .find_iter("at -0044-03-15")
+      .map(|(r, dt)| (r, dt.date().unwrap().year()))
+      .collect::<Vec<_>>()
+  };
+  check!(year(Parser::new("%Y-%m-%d")) == [(3..14, -44)]);
+  check!(year(Parser::new("%Y-%m-%d").year_digits(4, 6)) == [(3..14, -44)]);
+  check!(year(Parser::new("%-Y-%m-%d")) == [(3..14, -44)]);
+
+  // Runs of digits without the format's literals are skipped.
+  let text = "id 20120421 9999 2012-04-21 2012 2012-04-22";
+  let parser = Parser::new("%Y-%m-%d");
+  check!(parser.find_iter(text).map(|(range, _)| range).collect::<Vec<_>>() == [17..27, 33..43]);
+  let parser = Parser::new("%Y%m%d-%H").allow_unicode_digits();
+  let found = parser.find_iter("x ٢٠١٢٠٤٢١-11 20120422-11").map(|(range, _)| range);
+  check!(found.collect::<Vec<_>>() == [2..21, 22..33]);
+
+  // Custom names need not begin with a letter.
+  let parser = Parser::new("%b %Y").month_names(&[("1Q", 1)]).allow_partial_dates();
+  let found = parser.find_iter("x 1Q 2012").map(|(range, dt)| (range, dt.date().unwrap().month()));
+  check!(found.collect::<Vec<_>>() == [(2..9, 1)]);
+  Ok(())
+}
+
+#[test]
+fn test_parse_many() {
+  let parser = Parser::new("%Y-%m-%d");
+  let column = ["2012-04-21", "1776-07-04", "2012-04-22"];
+  let mut out = Vec::new();
+  check!(parser.parse_many(column, &mut out).is_ok());
+  check!(out.iter().map(|d| d.date().unwrap().ymd()).collect::<Vec<_>>()[1] == (1776, 7, 4));
+
+  let column = ["2012-04-21", "bogus", "2012-04-22"];
+  let mut out = Vec::new();
+  let (row, err) = parser.parse_many(column, &mut out).unwrap_err();
+  check!(row == 1);
+  check!(err.index == Some(0));
+  check!(out.len() == 1);
+
+  let (mut out, mut errors) = (Vec::new(), Vec::new());
+  parser.parse_many_lenient(column, &mut out, &mut errors);
+  check!(out.len() == 3);
+  check!(out[1].is_none());
+  check!(out[2].unwrap().date().unwrap().ymd() == (2012, 4, 22));
+  check!(errors.iter().map(|(row, _)| *row).collect::<Vec<_>>() == [1]);
+  #[cfg(feature = "alloc")]
+  check!(errors[0].1.src.is_empty());
+}
+
+#[test]
+fn test_fixed_width() -> ParseResult<()> {
+  check!(crate::fixed::width("%Y%m%d%H%M%S") == Some(14));
+  check!(crate::fixed::width("%Y-%m-%dT%H:%M:%S%.6f%z") == Some(31));
+  check!(crate::fixed::width("%-m/%-d/%Y").is_none());
+  check!(crate::fixed::width("%B %Y").is_none());
+  let dt = Parser::new("%Y%m%d%H%M%S").parse("20120421110005")?;
+  check!(dt.date()?.ymd() == (2012, 4, 21));
+  check!(dt.time()?.hms() == (11, 0, 5, 0));
+  let dt = Parser::new("%Y-%m-%dT%H:%M:%S%.6f%z").parse("2012-04-21T11:00:00.250000-0400")?;
+  check!(dt.time()?.hms() == (11, 0, 0, 250_000_000));
+  check!(dt.time()?.precision() == Some(TimePrecision::Fraction(6)));
+  check!(dt.time()?.utc_offset() == Some(-14400));
+  // Errors are still reported by the general parser.
+  let err = Parser::new("%Y%m%d").parse("2012O421").unwrap_err();
+  check!(err.index == Some(4));
+  check!(Parser::new("%Y%m%d").parse("201204211").unwrap_err().index == Some(8));
+  check!(Parser::new("%Y%m%d").parse_prefix("201204211")?.1 == "1");
+  check!(Parser::new("%Y%m").parse("201204").is_err()); // Incomplete date
+  Ok(())
+}
@@ -0,0 +1,87 @@
+//! Conversions into [`time`] types, enabled by the `time` feature.
+
+use time::Date;
+use time::Month;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::Time;
+use time::UtcOffset;
+
+use crate::error::ErrorKind;
+use crate::ParseError;
+use crate::ParseResult;
+use crate::RawDate;
+use crate::RawDateTime;
+use crate::RawTime;
+
+impl TryFrom<RawDate> for Date {
+  type Error = ParseError;
+
+  fn try_from(value: RawDate) -> ParseResult<Self> {
+    date(&value)
+  }
+}
+
+impl TryFrom<RawTime> for Time {
+  type Error = ParseError;
+
+  fn try_from(value: RawTime) -> ParseResult<Self> {
+    time(&value)
+  }
+}
+
+impl TryFrom<RawDateTime> for Date {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    date(&value.date()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for Time {
+  type Error = ParseError;
+
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    time(&value.time()?)
+  }
+}
+
+impl TryFrom<RawDateTime> for PrimitiveDateTime {
+  type Error = ParseError;
+
+  /// Convert to a [`PrimitiveDateTime`]. A missing time is treated as midnight, and the end of
+  /// the day (`24:00`) as midnight of the following day.
+  ///
+  /// The `time` crate does not support leap seconds, so they send `OutOfRange`.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    let (date, raw_time) = (date(&value.date()?)?, value.time.unwrap_or_default());
+    match raw_time.is_end_of_day() {
+      true => Ok(date.next_day().ok_or(ErrorKind::OutOfRange)?.midnight()),
+      false => Ok(PrimitiveDateTime::new(date, time(&raw_time)?)),
+    }
+  }
+}
+
+impl TryFrom<RawDateTime> for OffsetDateTime {
+  type Error = ParseError;
+
+  /// Convert to an [`OffsetDateTime`]. A missing time is treated as midnight, but a UTC offset is
+  /// required.
+  fn try_from(value: RawDateTime) -> ParseResult<Self> {
+    let offset = value.time.and_then(|t| t.utc_offset).ok_or(ErrorKind::MissingUtcOffset)?;
+    let offset = UtcOffset::from_whole_seconds(offset).map_err(|_| ErrorKind::OutOfRange)?;
+    Ok(PrimitiveDateTime::try_from(value)?.assume_offset(offset))
+  }
+}
+
+fn date(date: &RawDate) -> ParseResult<Date> {
+  date.assert_complete(false)?;
+  let out_of_range = |_| ParseError::from(ErrorKind::OutOfRange);
+  let month = Month::try_from(date.month()).map_err(out_of_range)?;
+  Date::from_calendar_date(date.year(), month, date.day()).map_err(out_of_range)
+}
+
+fn time(time: &RawTime) -> ParseResult<Time> {
+  Time::from_hms_nano(time.hour(), time.minute(), time.second(), time.nanosecond() as u32)
+    .map_err(|_| ParseError::from(ErrorKind::OutOfRange))
+}
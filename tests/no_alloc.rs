@@ -0,0 +1,51 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+use std::hint::black_box;
+
+use strptime::Parser;
+
+/// A global allocator that counts allocations, so that the tests can verify that successful
+/// parsing never allocates.
+struct CountingAllocator;
+
+thread_local! {
+  // Counted per thread, so that tests running concurrently do not interfere with each other.
+  static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn assert_no_allocations(fmt: &'static str, input: &str) {
+  let parser = Parser::new(fmt);
+  let before = ALLOCATIONS.with(Cell::get);
+  black_box(parser.parse(black_box(input)).expect("valid input"));
+  let allocations = ALLOCATIONS.with(Cell::get) - before;
+  assert_eq!(allocations, 0, "parsing {input:?} with {fmt:?} allocated {allocations} time(s)");
+}
+
+#[test]
+fn test_parse_does_not_allocate() {
+  assert_no_allocations("%Y-%m-%d", "2012-04-21");
+  assert_no_allocations("%Y-%m-%dT%H:%M:%S%.6f%z", "2012-04-21T11:00:00.000000-0400");
+  assert_no_allocations("%A, %B %-d, %Y %I:%M %p", "Saturday, April 21, 2012 11:00 AM");
+}
+
+#[test]
+fn test_fixed_width_does_not_allocate() {
+  assert_no_allocations("%Y%m%d%H%M%S", "20120421110000");
+  assert_no_allocations("%Y-%m-%dT%H:%M:%S", "2012-04-21T11:00:00");
+}